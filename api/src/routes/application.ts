@@ -106,6 +106,7 @@ router.delete('/:applicationId', async (req: AuthRequest, res, next) => {
         applicationAccount.createdAt
       )
       .accountsStrict({
+        config: derivePDAs.config()[0],
         application: applicationPubkey,
         applicant: userPublicKey,
       })
@@ -192,6 +193,7 @@ router.post('/:listing/cancel-approved/:applicant', async (req: AuthRequest, res
         applicationCreatedAt
       )
       .accountsStrict({
+        config: derivePDAs.config()[0],
         listing: listingPubkey,
         application: application.publicKey,
         signer: userPublicKey,
@@ -388,6 +390,7 @@ router.post('/:listing/approve/:applicant', async (req: AuthRequest, res, next)
         applicationCreatedAt
       )
       .accountsStrict({
+        config: derivePDAs.config()[0],
         listing: listingPubkey,
         application: application.publicKey,
         owner: userPublicKey,
@@ -464,6 +467,7 @@ router.post('/:listing/reject/:applicant', async (req: AuthRequest, res, next) =
         applicationCreatedAt
       )
       .accountsStrict({
+        config: derivePDAs.config()[0],
         listing: listingPubkey,
         application: application.publicKey,
         owner: userPublicKey,
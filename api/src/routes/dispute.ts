@@ -39,6 +39,7 @@ router.post('/raise', async (req: AuthRequest, res, next) => {
     const tx = await program.methods
       .raiseDispute(reason)
       .accountsStrict({
+        config: derivePDAs.config()[0],
         lease: leasePubkey,
         escrow: escrowPda,
         dispute: disputePda,
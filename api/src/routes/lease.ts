@@ -77,6 +77,7 @@ router.post('/create', async (req: AuthRequest, res, next) => {
         Array.from(contractUriBytes)
       )
       .accountsStrict({
+        config: derivePDAs.config()[0],
         listing: listingPubkey,
         application: application.publicKey,
         lease: leasePda,
@@ -132,6 +133,9 @@ router.post('/:lease/sign', async (req: AuthRequest, res, next) => {
       throw new ApiError(400, 'Already signed');
     }
 
+    // 簽約時可一併核准自動扣款額度
+    const { autoDebitCap } = req.body ?? {};
+
     const [configPda] = derivePDAs.config();
     const [escrowPda] = derivePDAs.escrow(leasePubkey);
     const [escrowTokenPda] = derivePDAs.escrowToken(leasePubkey);
@@ -145,7 +149,7 @@ router.post('/:lease/sign', async (req: AuthRequest, res, next) => {
     const feeReceiverToken = await getAssociatedTokenAddress(USDC_MINT, config.feeReceiver);
 
     const tx = await program.methods
-      .signLease()
+      .signLease(leaseAccount.termsRevision, autoDebitCap ? new BN(autoDebitCap) : null)
       .accountsStrict({
         config: configPda,
         listing: leaseAccount.listing,
@@ -330,6 +330,7 @@ router.put('/:publicKey', authenticateToken, requirePropertyCredential, async (r
         metadata ? Array.from(metadataUriBytes!) : null
       )
       .accountsStrict({
+        config: derivePDAs.config()[0],
         listing: listingPubkey,
         owner: userPublicKey,
       })
@@ -386,6 +387,7 @@ router.post('/:publicKey/toggle', authenticateToken, requirePropertyCredential,
     const tx = await program.methods
       .toggleListing()
       .accountsStrict({
+        config: derivePDAs.config()[0],
         listing: listingPubkey,
         owner: userPublicKey,
       })
@@ -4,7 +4,7 @@ import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from '@solana/spl-token';
 import { program, derivePDAs, USDC_MINT } from '../config/solana';
 import { ApiError } from '../middleware/errorHandler';
 import { AuthRequest } from '../middleware/auth';
-import { BN } from '@coral-xyz/anchor';
+import { BN, EventParser } from '@coral-xyz/anchor';
 import { broadcastToUser } from '../ws/websocket';
 
 const router = Router();
@@ -16,10 +16,20 @@ router.post('/rent/:lease', async (req: AuthRequest, res, next) => {
     const userPublicKey = new PublicKey(req.user!.publicKey);
     const leasePubkey = new PublicKey(lease);
 
+    // 一次支付的期數，預設 1 期
+    const months = req.body?.months === undefined ? 1 : Number(req.body.months);
+    if (!Number.isInteger(months) || months < 1 || months > 12) {
+      throw new ApiError(400, 'Invalid months');
+    }
+
     // 檢查租約
     const leaseAccount = await program.account.lease.fetch(leasePubkey);
-    if (!leaseAccount.tenant.equals(userPublicKey)) {
-      throw new ApiError(403, 'Not the tenant of this lease');
+    // 承租人本人或其允許的代繳人皆可支付
+    const isTenant = leaseAccount.tenant.equals(userPublicKey);
+    const isApprovedPayer = !leaseAccount.payerRestricted ||
+      leaseAccount.approvedPayers.some((payer) => payer.equals(userPublicKey));
+    if (!isTenant && !isApprovedPayer) {
+      throw new ApiError(403, 'Not allowed to pay rent for this lease');
     }
 
     // 檢查租約狀態
@@ -27,39 +37,23 @@ router.post('/rent/:lease', async (req: AuthRequest, res, next) => {
       throw new ApiError(400, 'Lease is not active');
     }
 
-    // 檢查是否需要支付
-    const now = Math.floor(Date.now() / 1000);
-    const startDate = leaseAccount.startDate.toNumber();
-    const endDate = leaseAccount.endDate.toNumber();
     const paidMonths = leaseAccount.paidMonths;
 
-    // 計算租期總月數
-    const totalMonths = Math.ceil((endDate - startDate) / (30 * 86400));
-    
-    // 簡化檢查：只要還沒付完且租約還在期限內就可以付
-    if (paidMonths >= totalMonths) {
-      throw new ApiError(400, 'All rent has been paid');
-    }
-    
-    if (now > endDate) {
-      throw new ApiError(400, 'Lease has ended');
-    }
-
     const [configPda] = derivePDAs.config();
     const config = await program.account.config.fetch(configPda);
 
     // 獲取 token 帳戶
-    const tenantToken = await getAssociatedTokenAddress(USDC_MINT, userPublicKey);
+    const payerToken = await getAssociatedTokenAddress(USDC_MINT, userPublicKey);
     const landlordToken = await getAssociatedTokenAddress(USDC_MINT, leaseAccount.landlord);
     const feeReceiverToken = await getAssociatedTokenAddress(USDC_MINT, config.feeReceiver);
 
     const tx = await program.methods
-      .payRent()
+      .payRent(months)
       .accountsStrict({
         config: configPda,
         lease: leasePubkey,
-        tenant: userPublicKey,
-        tenantToken,
+        payer: userPublicKey,
+        payerToken,
         landlordToken,
         feeReceiverToken,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -71,6 +65,20 @@ router.post('/rent/:lease', async (req: AuthRequest, res, next) => {
     tx.recentBlockhash = blockhash;
     tx.feePayer = userPublicKey;
 
+    // 模擬交易，由合約依繳費週期、調漲、折扣與逾期費計算應付期數與金額
+    const simulation = await program.provider.connection.simulateTransaction(tx);
+    if (simulation.value.err) {
+      throw new ApiError(400, `Rent payment rejected: ${JSON.stringify(simulation.value.err)}`);
+    }
+    const eventParser = new EventParser(program.programId, program.coder);
+    const charges = [...eventParser.parseLogs(simulation.value.logs ?? [])]
+      .filter((event) => event.name === 'rentPaid')
+      .map((event) => event.data as { rentAmount: BN; lateFee: BN });
+    const amount = charges.reduce(
+      (total, charge) => total.add(charge.rentAmount).add(charge.lateFee),
+      new BN(0)
+    );
+
     const serialized = tx.serialize({
       requireAllSignatures: false,
       verifySignatures: false
@@ -80,18 +88,23 @@ router.post('/rent/:lease', async (req: AuthRequest, res, next) => {
     broadcastToUser(leaseAccount.landlord.toString(), {
       type: 'rent_paid',
       lease: lease,
-      tenant: userPublicKey.toString(),
-      amount: leaseAccount.rent.toString(),
-      month: paidMonths + 1,
-      message: `收到第 ${paidMonths + 1} 個月租金`
+      tenant: leaseAccount.tenant.toString(),
+      payer: userPublicKey.toString(),
+      amount: amount.toString(),
+      month: paidMonths + months,
+      months,
+      message: months === 1
+        ? `收到第 ${paidMonths + 1} 期租金`
+        : `收到第 ${paidMonths + 1} 至 ${paidMonths + months} 期租金`
     });
 
     res.json({
       success: true,
       transaction: serialized.toString('base64'),
       payment: {
-        amount: leaseAccount.rent.toString(),
-        month: paidMonths + 1
+        amount: amount.toString(),
+        month: paidMonths + months,
+        months
       }
     });
   } catch (error) {
@@ -132,6 +145,7 @@ router.post('/deposit/:lease/release', async (req: AuthRequest, res, next) => {
         new BN(tenantAmount)
       )
       .accountsStrict({
+        config: derivePDAs.config()[0],
         lease: leasePubkey,
         escrow: escrowPda,
         signer: userPublicKey,
@@ -14,16 +14,61 @@ export type Zuvi = {
   },
   "instructions": [
     {
-      "name": "applyLease",
+      "name": "acceptAdmin",
       "discriminator": [
-        81,
-        96,
-        246,
-        245,
-        175,
-        150,
-        197,
-        122
+        112,
+        42,
+        45,
+        90,
+        116,
+        181,
+        13,
+        170
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "系統配置帳戶"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "newAdmin",
+          "docs": [
+            "待接受的新管理員"
+          ],
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptEarlyTermination",
+      "discriminator": [
+        184,
+        103,
+        127,
+        147,
+        83,
+        132,
+        243,
+        125
       ],
       "accounts": [
         {
@@ -46,6 +91,7 @@ export type Zuvi = {
         },
         {
           "name": "listing",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -66,244 +112,248 @@ export type Zuvi = {
           }
         },
         {
-          "name": "application",
+          "name": "lease",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  97,
-                  112,
-                  112,
                   108,
-                  121
+                  101,
+                  97,
+                  115,
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "listing"
+                "path": "lease.listing",
+                "account": "lease"
               },
               {
                 "kind": "account",
-                "path": "applicant"
+                "path": "lease.original_tenant",
+                "account": "lease"
               },
               {
-                "kind": "arg",
-                "path": "createdAt"
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
               }
             ]
           }
         },
         {
-          "name": "applicant",
+          "name": "escrow",
           "writable": true,
-          "signer": true
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease"
+              }
+            ]
+          }
         },
         {
-          "name": "apiSigner",
+          "name": "signer",
           "signer": true
         },
         {
-          "name": "tenantAttest"
+          "name": "escrowToken",
+          "writable": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "landlordToken",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         }
       ],
       "args": [
         {
-          "name": "messageUri",
+          "name": "penalty",
           "type": {
-            "array": [
-              "u8",
-              64
-            ]
+            "option": "u64"
           }
-        },
-        {
-          "name": "createdAt",
-          "type": "i64"
         }
       ]
     },
     {
-      "name": "approveApplication",
+      "name": "acceptRenewal",
       "discriminator": [
-        136,
-        47,
-        9,
-        33,
-        208,
-        120,
-        226,
-        157
+        207,
+        71,
+        77,
+        14,
+        10,
+        128,
+        67,
+        215
       ],
       "accounts": [
         {
-          "name": "listing",
-          "writable": true,
+          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
+                  99,
+                  111,
+                  110,
+                  102,
                   105,
-                  115,
-                  116
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "listing.property_attest",
-                "account": "listing"
               }
             ]
           }
         },
         {
-          "name": "application",
+          "name": "lease",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  97,
-                  112,
-                  112,
                   108,
-                  121
+                  101,
+                  97,
+                  115,
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "listing"
+                "path": "lease.listing",
+                "account": "lease"
               },
               {
-                "kind": "arg",
-                "path": "applicant"
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
               },
               {
-                "kind": "arg",
-                "path": "createdAt"
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
               }
             ]
           }
         },
         {
-          "name": "owner",
+          "name": "escrow",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tenant",
           "signer": true
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "applicant",
-          "type": "pubkey"
+          "name": "tenantToken",
+          "writable": true
         },
         {
-          "name": "createdAt",
-          "type": "i64"
+          "name": "landlordToken",
+          "writable": true
+        },
+        {
+          "name": "feeReceiverToken",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "cancelApprovedApplication",
+      "name": "applyLease",
       "discriminator": [
-        173,
-        152,
-        68,
-        90,
-        104,
-        34,
-        24,
-        11
+        81,
+        96,
+        246,
+        245,
+        175,
+        150,
+        197,
+        122
       ],
       "accounts": [
         {
-          "name": "listing",
-          "writable": true,
+          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
+                  99,
+                  111,
+                  110,
+                  102,
                   105,
-                  115,
-                  116
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "listing.property_attest",
-                "account": "listing"
               }
             ]
           }
         },
         {
-          "name": "application",
-          "writable": true,
+          "name": "listing",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  97,
-                  112,
-                  112,
                   108,
-                  121
+                  105,
+                  115,
+                  116
                 ]
               },
               {
                 "kind": "account",
-                "path": "application.listing",
-                "account": "application"
-              },
-              {
-                "kind": "arg",
-                "path": "applicant"
-              },
-              {
-                "kind": "arg",
-                "path": "createdAt"
+                "path": "listing.property_attest",
+                "account": "listing"
               }
             ]
           }
         },
-        {
-          "name": "signer",
-          "signer": true
-        }
-      ],
-      "args": [
-        {
-          "name": "applicant",
-          "type": "pubkey"
-        },
-        {
-          "name": "createdAt",
-          "type": "i64"
-        }
-      ]
-    },
-    {
-      "name": "closeApplication",
-      "discriminator": [
-        185,
-        123,
-        65,
-        93,
-        138,
-        249,
-        205,
-        150
-      ],
-      "accounts": [
         {
           "name": "application",
           "writable": true,
@@ -321,8 +371,7 @@ export type Zuvi = {
               },
               {
                 "kind": "account",
-                "path": "application.listing",
-                "account": "application"
+                "path": "listing"
               },
               {
                 "kind": "account",
@@ -339,12 +388,28 @@ export type Zuvi = {
           "name": "applicant",
           "writable": true,
           "signer": true
+        },
+        {
+          "name": "apiSigner",
+          "signer": true
+        },
+        {
+          "name": "tenantAttest"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "applicant",
-          "type": "pubkey"
+          "name": "messageUri",
+          "type": {
+            "array": [
+              "u8",
+              64
+            ]
+          }
         },
         {
           "name": "createdAt",
@@ -353,16 +418,16 @@ export type Zuvi = {
       ]
     },
     {
-      "name": "confirmRelease",
+      "name": "approveApplication",
       "discriminator": [
-        181,
-        157,
-        89,
-        7,
-        37,
-        54,
-        72,
-        90
+        136,
+        47,
+        9,
+        33,
+        208,
+        120,
+        226,
+        157
       ],
       "accounts": [
         {
@@ -406,164 +471,171 @@ export type Zuvi = {
           }
         },
         {
-          "name": "lease",
+          "name": "application",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
-                  101,
                   97,
-                  115,
-                  101
+                  112,
+                  112,
+                  108,
+                  121
                 ]
               },
               {
                 "kind": "account",
-                "path": "lease.listing",
-                "account": "lease"
+                "path": "listing"
               },
               {
-                "kind": "account",
-                "path": "lease.tenant",
-                "account": "lease"
+                "kind": "arg",
+                "path": "applicant"
               },
               {
-                "kind": "account",
-                "path": "lease.start_date",
-                "account": "lease"
+                "kind": "arg",
+                "path": "createdAt"
               }
             ]
           }
         },
         {
-          "name": "escrow",
+          "name": "owner",
+          "docs": [
+            "房東或具核准申請權限的管理人"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "applicant",
+          "type": "pubkey"
+        },
+        {
+          "name": "createdAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "cancelAdminTransfer",
+      "discriminator": [
+        38,
+        131,
+        157,
+        31,
+        240,
+        137,
+        44,
+        215
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "系統配置帳戶"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  101,
-                  115,
                   99,
-                  114,
                   111,
-                  119
+                  110,
+                  102,
+                  105,
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "lease"
               }
             ]
           }
         },
         {
-          "name": "signer",
+          "name": "admin",
+          "docs": [
+            "現任管理員"
+          ],
           "signer": true
-        },
-        {
-          "name": "escrowToken",
-          "writable": true
-        },
-        {
-          "name": "landlordToken",
-          "writable": true
-        },
-        {
-          "name": "tenantToken",
-          "writable": true
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         }
       ],
       "args": []
     },
     {
-      "name": "createLease",
+      "name": "cancelApprovedApplication",
       "discriminator": [
-        158,
-        42,
-        229,
-        17,
-        202,
-        87,
+        173,
+        152,
         68,
-        148
+        90,
+        104,
+        34,
+        24,
+        11
       ],
       "accounts": [
         {
-          "name": "listing",
+          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
+                  99,
+                  111,
+                  110,
+                  102,
                   105,
-                  115,
-                  116
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "listing.property_attest",
-                "account": "listing"
               }
             ]
           }
         },
         {
-          "name": "application",
+          "name": "listing",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  97,
-                  112,
-                  112,
                   108,
-                  121
+                  105,
+                  115,
+                  116
                 ]
               },
               {
                 "kind": "account",
-                "path": "listing"
-              },
-              {
-                "kind": "arg",
-                "path": "applicant"
-              },
-              {
-                "kind": "arg",
-                "path": "applicationCreatedAt"
+                "path": "listing.property_attest",
+                "account": "listing"
               }
             ]
           }
         },
         {
-          "name": "lease",
+          "name": "application",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
-                  101,
                   97,
-                  115,
-                  101
+                  112,
+                  112,
+                  108,
+                  121
                 ]
               },
               {
                 "kind": "account",
-                "path": "listing"
+                "path": "application.listing",
+                "account": "application"
               },
               {
                 "kind": "arg",
@@ -571,19 +643,14 @@ export type Zuvi = {
               },
               {
                 "kind": "arg",
-                "path": "startDate"
+                "path": "createdAt"
               }
             ]
           }
         },
         {
-          "name": "landlord",
-          "writable": true,
+          "name": "signer",
           "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
@@ -592,50 +659,26 @@ export type Zuvi = {
           "type": "pubkey"
         },
         {
-          "name": "applicationCreatedAt",
-          "type": "i64"
-        },
-        {
-          "name": "startDate",
+          "name": "createdAt",
           "type": "i64"
-        },
-        {
-          "name": "endDate",
-          "type": "i64"
-        },
-        {
-          "name": "paymentDay",
-          "type": "u8"
-        },
-        {
-          "name": "contractUri",
-          "type": {
-            "array": [
-              "u8",
-              64
-            ]
-          }
         }
       ]
     },
     {
-      "name": "createListing",
+      "name": "cancelEarlyTermination",
       "discriminator": [
-        18,
-        168,
-        45,
-        24,
-        191,
-        31,
-        117,
-        54
+        78,
+        230,
+        115,
+        122,
+        9,
+        7,
+        147,
+        172
       ],
       "accounts": [
         {
           "name": "config",
-          "docs": [
-            "系統配置"
-          ],
           "pda": {
             "seeds": [
               {
@@ -653,10 +696,7 @@ export type Zuvi = {
           }
         },
         {
-          "name": "listing",
-          "docs": [
-            "房源列表帳戶"
-          ],
+          "name": "lease",
           "writable": true,
           "pda": {
             "seeds": [
@@ -664,99 +704,52 @@ export type Zuvi = {
                 "kind": "const",
                 "value": [
                   108,
-                  105,
+                  101,
+                  97,
                   115,
-                  116
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "propertyAttest"
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
               }
             ]
           }
         },
         {
-          "name": "owner",
-          "docs": [
-            "房東（支付者）"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "apiSigner",
-          "docs": [
-            "API 簽名者"
-          ],
+          "name": "tenant",
           "signer": true
-        },
-        {
-          "name": "propertyAttest",
-          "docs": [
-            "產權憑證帳戶"
-          ]
-        },
-        {
-          "name": "systemProgram",
-          "docs": [
-            "系統程式"
-          ],
-          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "address",
-          "type": {
-            "array": [
-              "u8",
-              64
-            ]
-          }
-        },
-        {
-          "name": "buildingArea",
-          "type": "u32"
-        },
-        {
-          "name": "rent",
-          "type": "u64"
-        },
-        {
-          "name": "deposit",
-          "type": "u64"
-        },
-        {
-          "name": "metadataUri",
-          "type": {
-            "array": [
-              "u8",
-              64
-            ]
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "initialize",
+      "name": "cancelTermination",
       "discriminator": [
-        175,
-        175,
-        109,
-        31,
-        13,
-        152,
-        155,
-        237
+        229,
+        221,
+        163,
+        203,
+        223,
+        84,
+        65,
+        172
       ],
       "accounts": [
         {
           "name": "config",
-          "docs": [
-            "系統配置帳戶"
-          ],
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -773,60 +766,9 @@ export type Zuvi = {
             ]
           }
         },
-        {
-          "name": "authority",
-          "docs": [
-            "初始化授權者（支付者）"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "docs": [
-            "系統程式"
-          ],
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "apiSigner",
-          "type": "pubkey"
-        },
-        {
-          "name": "arbitrator",
-          "type": "pubkey"
-        },
-        {
-          "name": "feeReceiver",
-          "type": "pubkey"
-        },
-        {
-          "name": "usdcMint",
-          "type": "pubkey"
-        },
-        {
-          "name": "feeRate",
-          "type": "u16"
-        }
-      ]
-    },
-    {
-      "name": "initiateRelease",
-      "discriminator": [
-        207,
-        117,
-        219,
-        170,
-        61,
-        0,
-        71,
-        211
-      ],
-      "accounts": [
         {
           "name": "lease",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -846,7 +788,7 @@ export type Zuvi = {
               },
               {
                 "kind": "account",
-                "path": "lease.tenant",
+                "path": "lease.original_tenant",
                 "account": "lease"
               },
               {
@@ -857,56 +799,24 @@ export type Zuvi = {
             ]
           }
         },
-        {
-          "name": "escrow",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  101,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "lease"
-              }
-            ]
-          }
-        },
         {
           "name": "signer",
           "signer": true
         }
       ],
-      "args": [
-        {
-          "name": "landlordAmount",
-          "type": "u64"
-        },
-        {
-          "name": "tenantAmount",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "payRent",
+      "name": "claimFromGuarantor",
       "discriminator": [
-        69,
-        155,
-        112,
-        183,
-        178,
-        234,
-        94,
-        100
+        114,
+        36,
+        19,
+        54,
+        70,
+        71,
+        37,
+        104
       ],
       "accounts": [
         {
@@ -949,7 +859,7 @@ export type Zuvi = {
               },
               {
                 "kind": "account",
-                "path": "lease.tenant",
+                "path": "lease.original_tenant",
                 "account": "lease"
               },
               {
@@ -961,12 +871,11 @@ export type Zuvi = {
           }
         },
         {
-          "name": "tenant",
-          "writable": true,
+          "name": "landlord",
           "signer": true
         },
         {
-          "name": "tenantToken",
+          "name": "guarantorToken",
           "writable": true
         },
         {
@@ -985,129 +894,208 @@ export type Zuvi = {
       "args": []
     },
     {
-      "name": "raiseDispute",
+      "name": "closeApplication",
       "discriminator": [
-        41,
-        243,
-        1,
-        51,
-        150,
-        95,
-        246,
-        73
+        185,
+        123,
+        65,
+        93,
+        138,
+        249,
+        205,
+        150
       ],
       "accounts": [
         {
-          "name": "lease",
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "application",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
-                  101,
                   97,
-                  115,
-                  101
+                  112,
+                  112,
+                  108,
+                  121
                 ]
               },
               {
                 "kind": "account",
-                "path": "lease.listing",
-                "account": "lease"
+                "path": "application.listing",
+                "account": "application"
               },
               {
                 "kind": "account",
-                "path": "lease.tenant",
-                "account": "lease"
+                "path": "applicant"
               },
               {
-                "kind": "account",
-                "path": "lease.start_date",
-                "account": "lease"
+                "kind": "arg",
+                "path": "createdAt"
               }
             ]
           }
         },
         {
-          "name": "escrow",
+          "name": "applicant",
           "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "applicant",
+          "type": "pubkey"
+        },
+        {
+          "name": "createdAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "collectRent",
+      "discriminator": [
+        52,
+        165,
+        96,
+        165,
+        131,
+        15,
+        160,
+        36
+      ],
+      "accounts": [
+        {
+          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  101,
-                  115,
                   99,
-                  114,
                   111,
-                  119
+                  110,
+                  102,
+                  105,
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "lease"
               }
             ]
           }
         },
         {
-          "name": "dispute",
+          "name": "lease",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  100,
-                  105,
+                  108,
+                  101,
+                  97,
                   115,
-                  112,
-                  117,
-                  116,
                   101
                 ]
               },
               {
                 "kind": "account",
-                "path": "lease"
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
               }
             ]
           }
         },
         {
-          "name": "initiator",
-          "writable": true,
+          "name": "tenantToken",
+          "writable": true
+        },
+        {
+          "name": "landlordToken",
+          "writable": true
+        },
+        {
+          "name": "feeReceiverToken",
+          "writable": true
+        },
+        {
+          "name": "cranker",
+          "docs": [
+            "觸發扣款的任意帳戶"
+          ],
           "signer": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         }
       ],
-      "args": [
-        {
-          "name": "reason",
-          "type": "u8"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "rejectApplication",
+      "name": "completeLease",
       "discriminator": [
-        85,
-        73,
-        224,
-        47,
-        9,
-        184,
+        246,
         39,
-        217
+        111,
+        153,
+        223,
+        126,
+        40,
+        97
       ],
       "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "listing",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1128,62 +1116,56 @@ export type Zuvi = {
           }
         },
         {
-          "name": "application",
+          "name": "lease",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  97,
-                  112,
-                  112,
                   108,
-                  121
+                  101,
+                  97,
+                  115,
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "listing"
+                "path": "lease.listing",
+                "account": "lease"
               },
               {
-                "kind": "arg",
-                "path": "applicant"
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
               },
               {
-                "kind": "arg",
-                "path": "createdAt"
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
               }
             ]
           }
         },
         {
-          "name": "owner",
+          "name": "caller",
           "signer": true
         }
       ],
-      "args": [
-        {
-          "name": "applicant",
-          "type": "pubkey"
-        },
-        {
-          "name": "createdAt",
-          "type": "i64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "resolveDispute",
+      "name": "confirmRelease",
       "discriminator": [
-        231,
-        6,
-        202,
-        6,
-        96,
-        103,
-        12,
-        230
+        181,
+        157,
+        89,
+        7,
+        37,
+        54,
+        72,
+        90
       ],
       "accounts": [
         {
@@ -1247,7 +1229,7 @@ export type Zuvi = {
               },
               {
                 "kind": "account",
-                "path": "lease.tenant",
+                "path": "lease.original_tenant",
                 "account": "lease"
               },
               {
@@ -1282,31 +1264,7 @@ export type Zuvi = {
           }
         },
         {
-          "name": "dispute",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  100,
-                  105,
-                  115,
-                  112,
-                  117,
-                  116,
-                  101
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "lease"
-              }
-            ]
-          }
-        },
-        {
-          "name": "arbitrator",
+          "name": "signer",
           "signer": true
         },
         {
@@ -1326,30 +1284,21 @@ export type Zuvi = {
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         }
       ],
-      "args": [
-        {
-          "name": "landlordAmount",
-          "type": "u64"
-        },
-        {
-          "name": "tenantAmount",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "signLease",
+      "name": "confirmTermination",
       "discriminator": [
-        135,
-        105,
-        78,
-        179,
-        51,
-        45,
-        115,
-        174
-      ],
-      "accounts": [
+        228,
+        180,
+        71,
+        215,
+        103,
+        222,
+        195,
+        201
+      ],
+      "accounts": [
         {
           "name": "config",
           "pda": {
@@ -1412,7 +1361,7 @@ export type Zuvi = {
               },
               {
                 "kind": "account",
-                "path": "lease.tenant",
+                "path": "lease.original_tenant",
                 "account": "lease"
               },
               {
@@ -1447,87 +1396,107 @@ export type Zuvi = {
           }
         },
         {
-          "name": "tenant",
-          "writable": true,
+          "name": "signer",
           "signer": true
         },
         {
-          "name": "tenantToken",
+          "name": "escrowToken",
           "writable": true
         },
         {
-          "name": "landlordToken",
+          "name": "tenantToken",
           "writable": true
         },
         {
-          "name": "feeReceiverToken",
-          "writable": true
-        },
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createLease",
+      "discriminator": [
+        158,
+        42,
+        229,
+        17,
+        202,
+        87,
+        68,
+        148
+      ],
+      "accounts": [
         {
-          "name": "escrowToken",
-          "writable": true,
+          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  101,
-                  115,
                   99,
-                  114,
                   111,
-                  119,
-                  95,
-                  116,
-                  111,
-                  107,
-                  101,
-                  110
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "listing",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116
                 ]
               },
               {
                 "kind": "account",
-                "path": "lease"
+                "path": "listing.property_attest",
+                "account": "listing"
               }
             ]
           }
         },
         {
-          "name": "usdcMint"
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "application",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  112,
+                  112,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing"
+              },
+              {
+                "kind": "arg",
+                "path": "applicant"
+              },
+              {
+                "kind": "arg",
+                "path": "applicationCreatedAt"
+              }
+            ]
+          }
         },
         {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "toggleListing",
-      "discriminator": [
-        143,
-        108,
-        89,
-        47,
-        54,
-        242,
-        47,
-        220
-      ],
-      "accounts": [
-        {
-          "name": "listing",
-          "docs": [
-            "房源列表帳戶"
-          ],
+          "name": "lease",
           "writable": true,
           "pda": {
             "seeds": [
@@ -1535,42 +1504,106 @@ export type Zuvi = {
                 "kind": "const",
                 "value": [
                   108,
-                  105,
+                  101,
+                  97,
                   115,
-                  116
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "listing.property_attest",
-                "account": "listing"
+                "path": "listing"
+              },
+              {
+                "kind": "arg",
+                "path": "applicant"
+              },
+              {
+                "kind": "arg",
+                "path": "startDate"
               }
             ]
           }
         },
         {
-          "name": "owner",
+          "name": "landlord",
           "docs": [
-            "房東"
+            "房東或具建立租約權限的管理人"
           ],
+          "writable": true,
           "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "applicant",
+          "type": "pubkey"
+        },
+        {
+          "name": "applicationCreatedAt",
+          "type": "i64"
+        },
+        {
+          "name": "startDate",
+          "type": "i64"
+        },
+        {
+          "name": "endDate",
+          "type": "i64"
+        },
+        {
+          "name": "paymentDay",
+          "type": "u8"
+        },
+        {
+          "name": "contractUri",
+          "type": {
+            "array": [
+              "u8",
+              64
+            ]
+          }
+        }
+      ]
     },
     {
-      "name": "updateListing",
+      "name": "createListing",
       "discriminator": [
-        192,
-        174,
-        210,
-        68,
-        116,
-        40,
-        242,
-        253
+        18,
+        168,
+        45,
+        24,
+        191,
+        31,
+        117,
+        54
       ],
       "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "系統配置"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "listing",
           "docs": [
@@ -1590,8 +1623,7 @@ export type Zuvi = {
               },
               {
                 "kind": "account",
-                "path": "listing.property_attest",
-                "account": "listing"
+                "path": "propertyAttest"
               }
             ]
           }
@@ -1599,447 +1631,4741 @@ export type Zuvi = {
         {
           "name": "owner",
           "docs": [
-            "房東"
+            "房東（支付者）"
           ],
+          "writable": true,
           "signer": true
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "rent",
-          "type": {
-            "option": "u64"
-          }
+          "name": "apiSigner",
+          "docs": [
+            "API 簽名者"
+          ],
+          "signer": true
         },
         {
-          "name": "deposit",
-          "type": {
-            "option": "u64"
-          }
+          "name": "propertyAttest",
+          "docs": [
+            "產權憑證帳戶"
+          ]
         },
         {
-          "name": "metadataUri",
-          "type": {
-            "option": {
-              "array": [
-                "u8",
-                64
-              ]
-            }
+          "name": "systemProgram",
+          "docs": [
+            "系統程式"
+          ],
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "address",
+          "type": {
+            "array": [
+              "u8",
+              64
+            ]
+          }
+        },
+        {
+          "name": "buildingArea",
+          "type": "u32"
+        },
+        {
+          "name": "rent",
+          "type": "u64"
+        },
+        {
+          "name": "deposit",
+          "type": "u64"
+        },
+        {
+          "name": "metadataUri",
+          "type": {
+            "array": [
+              "u8",
+              64
+            ]
           }
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "application",
-      "discriminator": [
-        219,
-        9,
-        27,
-        113,
-        208,
-        126,
-        203,
-        30
-      ]
-    },
-    {
-      "name": "config",
-      "discriminator": [
-        155,
-        12,
-        170,
-        224,
-        30,
-        250,
-        204,
-        130
-      ]
     },
     {
-      "name": "dispute",
+      "name": "enableAutoDebit",
       "discriminator": [
-        36,
-        49,
-        241,
-        67,
-        40,
-        36,
-        241,
-        74
+        223,
+        231,
+        26,
+        235,
+        169,
+        31,
+        223,
+        150
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tenant",
+          "signer": true
+        },
+        {
+          "name": "tenantToken",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "autoDebitCap",
+          "type": "u64"
+        }
       ]
     },
     {
-      "name": "escrow",
+      "name": "initialize",
       "discriminator": [
+        175,
+        175,
+        109,
         31,
-        213,
-        123,
-        187,
-        186,
-        22,
-        218,
-        155
+        13,
+        152,
+        155,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "系統配置帳戶"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "初始化授權者（支付者），同時成為管理員"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "docs": [
+            "系統程式"
+          ],
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "apiSigner",
+          "type": "pubkey"
+        },
+        {
+          "name": "arbitrator",
+          "type": "pubkey"
+        },
+        {
+          "name": "feeReceiver",
+          "type": "pubkey"
+        },
+        {
+          "name": "usdcMint",
+          "type": "pubkey"
+        },
+        {
+          "name": "feeRate",
+          "type": "u16"
+        }
       ]
     },
     {
-      "name": "lease",
+      "name": "initiateRelease",
       "discriminator": [
-        14,
-        103,
-        218,
+        207,
+        117,
+        219,
+        170,
         61,
-        248,
-        234,
-        105,
-        84
-      ]
-    },
-    {
-      "name": "listing",
-      "discriminator": [
-        218,
-        32,
-        50,
-        73,
-        43,
-        134,
-        26,
-        58
-      ]
-    }
-  ],
+        0,
+        71,
+        211
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "signer",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "landlordAmount",
+          "type": "u64"
+        },
+        {
+          "name": "tenantAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrateTenant",
+      "discriminator": [
+        234,
+        45,
+        223,
+        116,
+        6,
+        130,
+        125,
+        244
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.property_attest",
+                "account": "listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oldTenant",
+          "docs": [
+            "原承租人"
+          ],
+          "signer": true
+        },
+        {
+          "name": "newTenant",
+          "signer": true
+        },
+        {
+          "name": "apiSigner",
+          "docs": [
+            "API 簽名者"
+          ],
+          "signer": true
+        },
+        {
+          "name": "tenantAttest",
+          "docs": [
+            "新錢包的承租人憑證帳戶"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "offerRenewal",
+      "discriminator": [
+        118,
+        27,
+        236,
+        223,
+        109,
+        162,
+        194,
+        106
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "landlord",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newEndDate",
+          "type": "i64"
+        },
+        {
+          "name": "newRent",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "payRent",
+      "discriminator": [
+        69,
+        155,
+        112,
+        183,
+        178,
+        234,
+        94,
+        100
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "承租人或代繳人"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "payerToken",
+          "writable": true
+        },
+        {
+          "name": "landlordToken",
+          "writable": true
+        },
+        {
+          "name": "feeReceiverToken",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "months",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "payRentShare",
+      "discriminator": [
+        55,
+        150,
+        72,
+        18,
+        237,
+        145,
+        68,
+        157
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "承租人或代繳人"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "payerToken",
+          "writable": true
+        },
+        {
+          "name": "landlordToken",
+          "writable": true
+        },
+        {
+          "name": "feeReceiverToken",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "forTenant",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "prepayRent",
+      "discriminator": [
+        115,
+        30,
+        83,
+        58,
+        87,
+        198,
+        154,
+        121
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "承租人或代繳人"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "payerToken",
+          "writable": true
+        },
+        {
+          "name": "landlordToken",
+          "writable": true
+        },
+        {
+          "name": "feeReceiverToken",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "months",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "proposeAdmin",
+      "discriminator": [
+        121,
+        214,
+        199,
+        212,
+        87,
+        39,
+        117,
+        234
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "系統配置帳戶"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "docs": [
+            "現任管理員"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "proposeTermination",
+      "discriminator": [
+        46,
+        45,
+        162,
+        110,
+        7,
+        118,
+        70,
+        255
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "signer",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "terminationDate",
+          "type": "i64"
+        },
+        {
+          "name": "refundAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "raiseDispute",
+      "discriminator": [
+        41,
+        243,
+        1,
+        51,
+        150,
+        95,
+        246,
+        73
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dispute",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "initiator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "rejectApplication",
+      "discriminator": [
+        85,
+        73,
+        224,
+        47,
+        9,
+        184,
+        39,
+        217
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "listing",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.property_attest",
+                "account": "listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "application",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  112,
+                  112,
+                  108,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing"
+              },
+              {
+                "kind": "arg",
+                "path": "applicant"
+              },
+              {
+                "kind": "arg",
+                "path": "createdAt"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "applicant",
+          "type": "pubkey"
+        },
+        {
+          "name": "createdAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "requestEarlyTermination",
+      "discriminator": [
+        250,
+        86,
+        222,
+        77,
+        96,
+        183,
+        191,
+        67
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tenant",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "terminationDate",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "resolveDispute",
+      "discriminator": [
+        231,
+        6,
+        202,
+        6,
+        96,
+        103,
+        12,
+        230
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.property_attest",
+                "account": "listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dispute",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "arbitrator",
+          "signer": true
+        },
+        {
+          "name": "escrowToken",
+          "writable": true
+        },
+        {
+          "name": "landlordToken",
+          "writable": true
+        },
+        {
+          "name": "tenantToken",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "landlordAmount",
+          "type": "u64"
+        },
+        {
+          "name": "tenantAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setCoTenants",
+      "discriminator": [
+        230,
+        134,
+        118,
+        64,
+        175,
+        137,
+        224,
+        139
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "landlord",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "coTenants",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "sharesBps",
+          "type": {
+            "vec": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setGuarantor",
+      "discriminator": [
+        126,
+        49,
+        238,
+        37,
+        87,
+        187,
+        223,
+        240
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "landlord",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "guarantor",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setLateFeePolicy",
+      "discriminator": [
+        93,
+        255,
+        42,
+        173,
+        231,
+        99,
+        73,
+        193
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "landlord",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "graceDays",
+          "type": "u8"
+        },
+        {
+          "name": "lateFeeType",
+          "type": "u8"
+        },
+        {
+          "name": "lateFeeValue",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setListingManager",
+      "discriminator": [
+        94,
+        3,
+        205,
+        157,
+        94,
+        82,
+        245,
+        221
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "系統配置"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "listing",
+          "docs": [
+            "房源列表帳戶"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.property_attest",
+                "account": "listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "docs": [
+            "房東"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "manager",
+          "type": {
+            "option": "pubkey"
+          }
+        },
+        {
+          "name": "permissions",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setPause",
+      "discriminator": [
+        63,
+        32,
+        154,
+        2,
+        56,
+        103,
+        79,
+        45
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "系統配置帳戶"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "守護者"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "u64"
+        },
+        {
+          "name": "allowExitWhenPaused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setPaymentSchedule",
+      "discriminator": [
+        194,
+        79,
+        208,
+        104,
+        205,
+        90,
+        229,
+        184
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "landlord",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paymentFrequency",
+          "type": "u8"
+        },
+        {
+          "name": "paymentDay",
+          "type": "u8"
+        },
+        {
+          "name": "utcOffsetMinutes",
+          "type": "i16"
+        }
+      ]
+    },
+    {
+      "name": "setPrepayDiscount",
+      "discriminator": [
+        205,
+        55,
+        157,
+        79,
+        249,
+        115,
+        88,
+        186
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "landlord",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "discountBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setRentEscalation",
+      "discriminator": [
+        233,
+        152,
+        30,
+        216,
+        241,
+        146,
+        79,
+        205
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "landlord",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "escalationBps",
+          "type": "u16"
+        },
+        {
+          "name": "escalationCapBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setRentPayers",
+      "discriminator": [
+        234,
+        174,
+        168,
+        65,
+        60,
+        133,
+        99,
+        197
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tenant",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "payerRestricted",
+          "type": "bool"
+        },
+        {
+          "name": "approvedPayers",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "signGuarantee",
+      "discriminator": [
+        130,
+        36,
+        44,
+        15,
+        152,
+        101,
+        3,
+        169
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "guarantor",
+          "signer": true
+        },
+        {
+          "name": "guarantorToken",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "guaranteeCap",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "signLease",
+      "discriminator": [
+        135,
+        105,
+        78,
+        179,
+        51,
+        45,
+        115,
+        174
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.property_attest",
+                "account": "listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tenant",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tenantToken",
+          "writable": true
+        },
+        {
+          "name": "landlordToken",
+          "writable": true
+        },
+        {
+          "name": "feeReceiverToken",
+          "writable": true
+        },
+        {
+          "name": "escrowToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  116,
+                  111,
+                  107,
+                  101,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "termsRevision",
+          "type": "u32"
+        },
+        {
+          "name": "autoDebitCap",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "signLeaseCoTenant",
+      "discriminator": [
+        207,
+        220,
+        227,
+        141,
+        134,
+        203,
+        244,
+        208
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "coTenant",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "terminateForArrears",
+      "discriminator": [
+        98,
+        129,
+        144,
+        133,
+        3,
+        204,
+        69,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.property_attest",
+                "account": "listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "landlord",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "toggleListing",
+      "discriminator": [
+        143,
+        108,
+        89,
+        47,
+        54,
+        242,
+        47,
+        220
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "系統配置"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "listing",
+          "docs": [
+            "房源列表帳戶"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.property_attest",
+                "account": "listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "docs": [
+            "房東或具上下架權限的管理人"
+          ],
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferListingOwnership",
+      "discriminator": [
+        149,
+        69,
+        107,
+        181,
+        25,
+        185,
+        38,
+        233
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "系統配置"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "listing",
+          "docs": [
+            "房源列表帳戶"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.property_attest",
+                "account": "listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lease",
+          "docs": [
+            "生效中的租約（如果有）"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  101,
+                  97,
+                  115,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "lease.listing",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.original_tenant",
+                "account": "lease"
+              },
+              {
+                "kind": "account",
+                "path": "lease.start_date",
+                "account": "lease"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "租約的押金託管帳戶（有租約時必填）"
+          ],
+          "optional": true
+        },
+        {
+          "name": "seller",
+          "docs": [
+            "原房東"
+          ],
+          "signer": true
+        },
+        {
+          "name": "buyer",
+          "docs": [
+            "新房東"
+          ],
+          "signer": true
+        },
+        {
+          "name": "apiSigner",
+          "docs": [
+            "API 簽名者"
+          ],
+          "signer": true
+        },
+        {
+          "name": "ownerAttest",
+          "docs": [
+            "買方的新產權憑證帳戶"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateConfig",
+      "discriminator": [
+        29,
+        158,
+        252,
+        191,
+        10,
+        83,
+        219,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "系統配置帳戶"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "docs": [
+            "管理員"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "apiSigner",
+          "type": {
+            "option": "pubkey"
+          }
+        },
+        {
+          "name": "arbitrator",
+          "type": {
+            "option": "pubkey"
+          }
+        },
+        {
+          "name": "feeReceiver",
+          "type": {
+            "option": "pubkey"
+          }
+        },
+        {
+          "name": "feeRate",
+          "type": {
+            "option": "u16"
+          }
+        },
+        {
+          "name": "guardian",
+          "type": {
+            "option": "pubkey"
+          }
+        },
+        {
+          "name": "arrearsTerminationMonths",
+          "type": {
+            "option": "u8"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateListing",
+      "discriminator": [
+        192,
+        174,
+        210,
+        68,
+        116,
+        40,
+        242,
+        253
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "系統配置"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "listing",
+          "docs": [
+            "房源列表帳戶"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "listing.property_attest",
+                "account": "listing"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "docs": [
+            "房東或具更新權限的管理人"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "rent",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "deposit",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "metadataUri",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "application",
+      "discriminator": [
+        219,
+        9,
+        27,
+        113,
+        208,
+        126,
+        203,
+        30
+      ]
+    },
+    {
+      "name": "config",
+      "discriminator": [
+        155,
+        12,
+        170,
+        224,
+        30,
+        250,
+        204,
+        130
+      ]
+    },
+    {
+      "name": "dispute",
+      "discriminator": [
+        36,
+        49,
+        241,
+        67,
+        40,
+        36,
+        241,
+        74
+      ]
+    },
+    {
+      "name": "escrow",
+      "discriminator": [
+        31,
+        213,
+        123,
+        187,
+        186,
+        22,
+        218,
+        155
+      ]
+    },
+    {
+      "name": "lease",
+      "discriminator": [
+        14,
+        103,
+        218,
+        61,
+        248,
+        234,
+        105,
+        84
+      ]
+    },
+    {
+      "name": "listing",
+      "discriminator": [
+        218,
+        32,
+        50,
+        73,
+        43,
+        134,
+        26,
+        58
+      ]
+    }
+  ],
   "events": [
     {
-      "name": "applicationApproved",
-      "discriminator": [
-        15,
-        107,
-        157,
-        97,
-        85,
-        208,
-        97,
-        93
-      ]
+      "name": "adminTransferAccepted",
+      "discriminator": [
+        79,
+        229,
+        204,
+        202,
+        134,
+        43,
+        177,
+        26
+      ]
+    },
+    {
+      "name": "adminTransferCancelled",
+      "discriminator": [
+        93,
+        23,
+        69,
+        55,
+        216,
+        128,
+        106,
+        56
+      ]
+    },
+    {
+      "name": "adminTransferProposed",
+      "discriminator": [
+        203,
+        168,
+        175,
+        51,
+        239,
+        104,
+        20,
+        85
+      ]
+    },
+    {
+      "name": "applicationApproved",
+      "discriminator": [
+        15,
+        107,
+        157,
+        97,
+        85,
+        208,
+        97,
+        93
+      ]
+    },
+    {
+      "name": "applicationCancelled",
+      "discriminator": [
+        86,
+        109,
+        21,
+        30,
+        91,
+        51,
+        148,
+        1
+      ]
+    },
+    {
+      "name": "applicationClosed",
+      "discriminator": [
+        130,
+        106,
+        177,
+        114,
+        127,
+        70,
+        89,
+        16
+      ]
+    },
+    {
+      "name": "applicationRejected",
+      "discriminator": [
+        123,
+        146,
+        55,
+        77,
+        187,
+        177,
+        232,
+        140
+      ]
+    },
+    {
+      "name": "applicationSubmitted",
+      "discriminator": [
+        202,
+        125,
+        149,
+        115,
+        111,
+        233,
+        172,
+        132
+      ]
+    },
+    {
+      "name": "autoDebitSet",
+      "discriminator": [
+        3,
+        214,
+        17,
+        48,
+        255,
+        63,
+        166,
+        34
+      ]
+    },
+    {
+      "name": "coTenantSigned",
+      "discriminator": [
+        73,
+        3,
+        83,
+        101,
+        195,
+        122,
+        77,
+        152
+      ]
+    },
+    {
+      "name": "coTenantsSet",
+      "discriminator": [
+        197,
+        152,
+        60,
+        0,
+        110,
+        29,
+        193,
+        37
+      ]
+    },
+    {
+      "name": "configUpdated",
+      "discriminator": [
+        40,
+        241,
+        230,
+        122,
+        11,
+        19,
+        198,
+        194
+      ]
+    },
+    {
+      "name": "disputeRaised",
+      "discriminator": [
+        246,
+        167,
+        109,
+        37,
+        142,
+        45,
+        38,
+        176
+      ]
+    },
+    {
+      "name": "disputeResolved",
+      "discriminator": [
+        121,
+        64,
+        249,
+        153,
+        139,
+        128,
+        236,
+        187
+      ]
+    },
+    {
+      "name": "earlyTerminationAccepted",
+      "discriminator": [
+        210,
+        134,
+        64,
+        21,
+        90,
+        108,
+        144,
+        118
+      ]
+    },
+    {
+      "name": "earlyTerminationCancelled",
+      "discriminator": [
+        154,
+        243,
+        20,
+        91,
+        118,
+        223,
+        177,
+        130
+      ]
+    },
+    {
+      "name": "earlyTerminationRequested",
+      "discriminator": [
+        99,
+        38,
+        141,
+        119,
+        174,
+        130,
+        196,
+        88
+      ]
+    },
+    {
+      "name": "guaranteeSigned",
+      "discriminator": [
+        248,
+        64,
+        38,
+        215,
+        162,
+        137,
+        169,
+        182
+      ]
+    },
+    {
+      "name": "guarantorClaimed",
+      "discriminator": [
+        187,
+        121,
+        134,
+        169,
+        123,
+        121,
+        165,
+        211
+      ]
+    },
+    {
+      "name": "guarantorSet",
+      "discriminator": [
+        70,
+        249,
+        242,
+        239,
+        10,
+        107,
+        21,
+        149
+      ]
+    },
+    {
+      "name": "lateFeePolicySet",
+      "discriminator": [
+        105,
+        84,
+        30,
+        141,
+        84,
+        122,
+        7,
+        182
+      ]
+    },
+    {
+      "name": "leaseCompleted",
+      "discriminator": [
+        194,
+        61,
+        116,
+        231,
+        250,
+        125,
+        89,
+        59
+      ]
+    },
+    {
+      "name": "leaseCreated",
+      "discriminator": [
+        203,
+        18,
+        89,
+        131,
+        118,
+        205,
+        117,
+        1
+      ]
+    },
+    {
+      "name": "leaseSigned",
+      "discriminator": [
+        66,
+        243,
+        252,
+        190,
+        196,
+        0,
+        51,
+        237
+      ]
+    },
+    {
+      "name": "leaseTerminatedForArrears",
+      "discriminator": [
+        26,
+        252,
+        45,
+        121,
+        65,
+        127,
+        128,
+        254
+      ]
+    },
+    {
+      "name": "listingCreated",
+      "discriminator": [
+        94,
+        164,
+        167,
+        255,
+        246,
+        186,
+        12,
+        96
+      ]
+    },
+    {
+      "name": "listingManagerSet",
+      "discriminator": [
+        65,
+        207,
+        73,
+        69,
+        78,
+        254,
+        241,
+        148
+      ]
+    },
+    {
+      "name": "listingOwnershipTransferred",
+      "discriminator": [
+        239,
+        43,
+        146,
+        237,
+        47,
+        89,
+        0,
+        138
+      ]
+    },
+    {
+      "name": "listingToggled",
+      "discriminator": [
+        183,
+        99,
+        197,
+        79,
+        92,
+        11,
+        125,
+        164
+      ]
+    },
+    {
+      "name": "listingUpdated",
+      "discriminator": [
+        190,
+        215,
+        199,
+        138,
+        255,
+        248,
+        98,
+        62
+      ]
+    },
+    {
+      "name": "pauseChanged",
+      "discriminator": [
+        238,
+        188,
+        213,
+        78,
+        134,
+        209,
+        178,
+        218
+      ]
+    },
+    {
+      "name": "paymentScheduleSet",
+      "discriminator": [
+        232,
+        237,
+        101,
+        48,
+        201,
+        199,
+        214,
+        107
+      ]
+    },
+    {
+      "name": "prepayDiscountSet",
+      "discriminator": [
+        136,
+        241,
+        91,
+        175,
+        70,
+        42,
+        157,
+        157
+      ]
+    },
+    {
+      "name": "releaseConfirmed",
+      "discriminator": [
+        246,
+        75,
+        82,
+        230,
+        221,
+        220,
+        198,
+        154
+      ]
+    },
+    {
+      "name": "releaseInitiated",
+      "discriminator": [
+        239,
+        5,
+        143,
+        89,
+        141,
+        107,
+        204,
+        240
+      ]
+    },
+    {
+      "name": "renewalAccepted",
+      "discriminator": [
+        194,
+        70,
+        207,
+        112,
+        146,
+        86,
+        198,
+        142
+      ]
+    },
+    {
+      "name": "renewalOffered",
+      "discriminator": [
+        132,
+        186,
+        158,
+        51,
+        27,
+        204,
+        165,
+        34
+      ]
+    },
+    {
+      "name": "rentContributed",
+      "discriminator": [
+        236,
+        220,
+        41,
+        58,
+        8,
+        245,
+        64,
+        233
+      ]
+    },
+    {
+      "name": "rentEscalationSet",
+      "discriminator": [
+        124,
+        249,
+        125,
+        220,
+        134,
+        152,
+        182,
+        157
+      ]
+    },
+    {
+      "name": "rentPaid",
+      "discriminator": [
+        140,
+        29,
+        172,
+        69,
+        152,
+        38,
+        73,
+        241
+      ]
+    },
+    {
+      "name": "rentPayersSet",
+      "discriminator": [
+        15,
+        170,
+        226,
+        213,
+        232,
+        199,
+        138,
+        172
+      ]
+    },
+    {
+      "name": "tenantMigrated",
+      "discriminator": [
+        94,
+        134,
+        136,
+        43,
+        217,
+        245,
+        199,
+        98
+      ]
+    },
+    {
+      "name": "terminationCancelled",
+      "discriminator": [
+        107,
+        132,
+        209,
+        101,
+        155,
+        82,
+        231,
+        96
+      ]
+    },
+    {
+      "name": "terminationConfirmed",
+      "discriminator": [
+        98,
+        117,
+        218,
+        98,
+        40,
+        238,
+        229,
+        65
+      ]
+    },
+    {
+      "name": "terminationProposed",
+      "discriminator": [
+        122,
+        196,
+        244,
+        248,
+        199,
+        69,
+        114,
+        41
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "notInitialized",
+      "msg": "E001: 系統尚未初始化"
+    },
+    {
+      "code": 6001,
+      "name": "unauthorized",
+      "msg": "E002: 無權限執行此操作"
+    },
+    {
+      "code": 6002,
+      "name": "listingAlreadyRented",
+      "msg": "E003: 房源已出租"
+    },
+    {
+      "code": 6003,
+      "name": "invalidApplication",
+      "msg": "E004: 申請不存在或狀態不正確"
+    },
+    {
+      "code": 6004,
+      "name": "leaseNotActive",
+      "msg": "E005: 租約未生效"
+    },
+    {
+      "code": 6005,
+      "name": "notSigned",
+      "msg": "E006: 尚未簽署"
+    },
+    {
+      "code": 6006,
+      "name": "alreadySigned",
+      "msg": "E007: 已經簽署"
+    },
+    {
+      "code": 6007,
+      "name": "disputeInProgress",
+      "msg": "E008: 爭議進行中，無法執行"
+    },
+    {
+      "code": 6008,
+      "name": "invalidParameter",
+      "msg": "E009: 無效的參數"
+    },
+    {
+      "code": 6009,
+      "name": "apiSignatureRequired",
+      "msg": "E010: 需要 API 簽名"
+    },
+    {
+      "code": 6010,
+      "name": "listingInactive",
+      "msg": "E011: 房源已下架"
+    },
+    {
+      "code": 6011,
+      "name": "duplicateApplication",
+      "msg": "E012: 重複申請"
+    },
+    {
+      "code": 6012,
+      "name": "leaseAlreadyExists",
+      "msg": "E013: 租約已存在"
+    },
+    {
+      "code": 6013,
+      "name": "paymentNotDue",
+      "msg": "E014: 支付日尚未到期"
+    },
+    {
+      "code": 6014,
+      "name": "leaseEnded",
+      "msg": "E015: 租約已結束"
+    },
+    {
+      "code": 6015,
+      "name": "amountMismatch",
+      "msg": "E016: 金額不匹配"
+    },
+    {
+      "code": 6016,
+      "name": "disputeAlreadyResolved",
+      "msg": "E017: 爭議已解決"
+    },
+    {
+      "code": 6017,
+      "name": "notArbitrator",
+      "msg": "E018: 非仲裁者"
+    },
+    {
+      "code": 6018,
+      "name": "depositAlreadyReleased",
+      "msg": "E019: 押金已釋放"
     },
     {
-      "name": "applicationCancelled",
-      "discriminator": [
-        86,
-        109,
-        21,
-        30,
-        91,
-        51,
-        148,
-        1
-      ]
+      "code": 6019,
+      "name": "invalidDate",
+      "msg": "E020: 無效的日期"
     },
     {
-      "name": "applicationClosed",
-      "discriminator": [
-        130,
-        106,
-        177,
-        114,
-        127,
-        70,
-        89,
-        16
-      ]
+      "code": 6020,
+      "name": "invalidFeeRate",
+      "msg": "E021: 無效的費率"
     },
     {
-      "name": "applicationRejected",
-      "discriminator": [
-        123,
-        146,
-        55,
-        77,
-        187,
-        177,
-        232,
-        140
-      ]
+      "code": 6021,
+      "name": "invalidDepositAmount",
+      "msg": "E022: 無效的押金金額"
     },
     {
-      "name": "applicationSubmitted",
-      "discriminator": [
-        202,
-        125,
-        149,
-        115,
-        111,
-        233,
-        172,
-        132
-      ]
+      "code": 6022,
+      "name": "invalidPaymentDay",
+      "msg": "E023: 無效的支付日"
     },
     {
-      "name": "disputeRaised",
-      "discriminator": [
-        246,
-        167,
-        109,
-        37,
-        142,
-        45,
-        38,
-        176
-      ]
+      "code": 6023,
+      "name": "invalidDisputeReason",
+      "msg": "E024: 無效的爭議原因"
     },
     {
-      "name": "disputeResolved",
-      "discriminator": [
-        121,
-        64,
-        249,
-        153,
-        139,
-        128,
-        236,
-        187
-      ]
+      "code": 6024,
+      "name": "cannotApplyOwnListing",
+      "msg": "E025: 無法對自己的房源申請"
     },
     {
-      "name": "leaseCreated",
-      "discriminator": [
-        203,
-        18,
-        89,
-        131,
-        118,
-        205,
-        117,
-        1
-      ]
+      "code": 6025,
+      "name": "notAdmin",
+      "msg": "E026: 非管理員"
     },
     {
-      "name": "leaseSigned",
-      "discriminator": [
-        66,
-        243,
-        252,
-        190,
-        196,
-        0,
-        51,
-        237
-      ]
+      "code": 6026,
+      "name": "noPendingAdminTransfer",
+      "msg": "E027: 沒有待接受的管理員轉移"
     },
     {
-      "name": "listingCreated",
-      "discriminator": [
-        94,
-        164,
-        167,
-        255,
-        246,
-        186,
-        12,
-        96
-      ]
+      "code": 6027,
+      "name": "programPaused",
+      "msg": "E028: 程式已暫停"
     },
     {
-      "name": "listingToggled",
-      "discriminator": [
-        183,
-        99,
-        197,
-        79,
-        92,
-        11,
-        125,
-        164
-      ]
+      "code": 6028,
+      "name": "notGuardian",
+      "msg": "E029: 非守護者"
     },
     {
-      "name": "listingUpdated",
-      "discriminator": [
-        190,
-        215,
-        199,
-        138,
-        255,
-        248,
-        98,
-        62
-      ]
+      "code": 6029,
+      "name": "leaseNotEnded",
+      "msg": "E030: 租約尚未到期"
     },
     {
-      "name": "releaseConfirmed",
-      "discriminator": [
-        246,
-        75,
-        82,
-        230,
-        221,
-        220,
-        198,
-        154
-      ]
+      "code": 6030,
+      "name": "rentOutstanding",
+      "msg": "E031: 尚有未繳租金"
     },
     {
-      "name": "releaseInitiated",
-      "discriminator": [
-        239,
-        5,
-        143,
-        89,
-        141,
-        107,
-        204,
-        240
-      ]
+      "code": 6031,
+      "name": "terminationAlreadyRequested",
+      "msg": "E032: 已有待處理的終止申請"
     },
     {
-      "name": "rentPaid",
-      "discriminator": [
-        140,
-        29,
-        172,
-        69,
-        152,
-        38,
-        73,
-        241
-      ]
+      "code": 6032,
+      "name": "noTerminationRequest",
+      "msg": "E033: 沒有待處理的終止申請"
+    },
+    {
+      "code": 6033,
+      "name": "invalidPenalty",
+      "msg": "E034: 違約金超過上限"
+    },
+    {
+      "code": 6034,
+      "name": "arrearsBelowThreshold",
+      "msg": "E035: 欠租未達終止門檻"
+    },
+    {
+      "code": 6035,
+      "name": "noRenewalOffer",
+      "msg": "E036: 沒有待接受的續約"
+    },
+    {
+      "code": 6036,
+      "name": "prepaymentExceedsLease",
+      "msg": "E037: 預付期數超過租期"
+    },
+    {
+      "code": 6037,
+      "name": "invalidMonth",
+      "msg": "E038: 無效的月份"
+    },
+    {
+      "code": 6038,
+      "name": "invalidDayOfMonth",
+      "msg": "E039: 無效的日"
+    },
+    {
+      "code": 6039,
+      "name": "dateOutOfRange",
+      "msg": "E040: 日期超出支援範圍"
+    },
+    {
+      "code": 6040,
+      "name": "autoDebitNotEnabled",
+      "msg": "E041: 未啟用自動扣款"
+    },
+    {
+      "code": 6041,
+      "name": "autoDebitAllowanceExceeded",
+      "msg": "E042: 自動扣款額度不足"
+    },
+    {
+      "code": 6042,
+      "name": "payerNotApproved",
+      "msg": "E043: 非承租人指定的代繳人"
+    },
+    {
+      "code": 6043,
+      "name": "coTenantsNotSigned",
+      "msg": "E044: 共同承租人尚未全部簽署"
+    },
+    {
+      "code": 6044,
+      "name": "alreadyContributed",
+      "msg": "E045: 本期分攤租金已繳納"
+    },
+    {
+      "code": 6045,
+      "name": "notCoTenant",
+      "msg": "E046: 非共同承租人"
+    },
+    {
+      "code": 6046,
+      "name": "partialContributionPending",
+      "msg": "E047: 本期已有部分分攤款項"
+    },
+    {
+      "code": 6047,
+      "name": "noGuarantor",
+      "msg": "E048: 租約未設定保證人"
+    },
+    {
+      "code": 6048,
+      "name": "guarantorNotSigned",
+      "msg": "E049: 保證人尚未簽署"
+    },
+    {
+      "code": 6049,
+      "name": "notInArrears",
+      "msg": "E050: 承租人未逾期欠租"
+    },
+    {
+      "code": 6050,
+      "name": "termsChanged",
+      "msg": "E051: 租約條款已變更"
     }
   ],
-  "errors": [
+  "types": [
     {
-      "code": 6000,
-      "name": "notInitialized",
-      "msg": "E001: 系統尚未初始化"
+      "name": "adminTransferAccepted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAdmin",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminTransferCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "cancelledAdmin",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "unauthorized",
-      "msg": "E002: 無權限執行此操作"
+      "name": "adminTransferProposed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "listingAlreadyRented",
-      "msg": "E003: 房源已出租"
+      "name": "application",
+      "docs": [
+        "租賃申請帳戶"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "listing",
+            "docs": [
+              "申請的房源"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "applicant",
+            "docs": [
+              "申請人公鑰"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "tenantAttest",
+            "docs": [
+              "承租人憑證公鑰"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "messageUri",
+            "docs": [
+              "IPFS Hash 存放申請資料"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          },
+          {
+            "name": "status",
+            "docs": [
+              "狀態: 0=待審, 1=核准, 2=拒絕"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "創建時間戳"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "invalidApplication",
-      "msg": "E004: 申請不存在或狀態不正確"
+      "name": "applicationApproved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "application",
+            "type": "pubkey"
+          },
+          {
+            "name": "listing",
+            "type": "pubkey"
+          },
+          {
+            "name": "applicant",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "leaseNotActive",
-      "msg": "E005: 租約未生效"
+      "name": "applicationCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "application",
+            "type": "pubkey"
+          },
+          {
+            "name": "listing",
+            "type": "pubkey"
+          },
+          {
+            "name": "applicant",
+            "type": "pubkey"
+          },
+          {
+            "name": "cancelledBy",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "notSigned",
-      "msg": "E006: 尚未簽署"
+      "name": "applicationClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "application",
+            "type": "pubkey"
+          },
+          {
+            "name": "listing",
+            "type": "pubkey"
+          },
+          {
+            "name": "applicant",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "alreadySigned",
-      "msg": "E007: 已經簽署"
+      "name": "applicationRejected",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "application",
+            "type": "pubkey"
+          },
+          {
+            "name": "listing",
+            "type": "pubkey"
+          },
+          {
+            "name": "applicant",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "disputeInProgress",
-      "msg": "E008: 爭議進行中，無法執行"
+      "name": "applicationSubmitted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "application",
+            "type": "pubkey"
+          },
+          {
+            "name": "listing",
+            "type": "pubkey"
+          },
+          {
+            "name": "applicant",
+            "type": "pubkey"
+          },
+          {
+            "name": "tenantAttest",
+            "type": "pubkey"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "invalidParameter",
-      "msg": "E009: 無效的參數"
+      "name": "autoDebitSet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lease",
+            "type": "pubkey"
+          },
+          {
+            "name": "tenant",
+            "type": "pubkey"
+          },
+          {
+            "name": "autoDebitCap",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "apiSignatureRequired",
-      "msg": "E010: 需要 API 簽名"
+      "name": "coTenant",
+      "docs": [
+        "共同承租人"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tenant",
+            "docs": [
+              "共同承租人公鑰"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "shareBps",
+            "docs": [
+              "租金分攤比例 (basis points)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "signed",
+            "docs": [
+              "是否已簽署"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "listingInactive",
-      "msg": "E011: 房源已下架"
+      "name": "coTenantSigned",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lease",
+            "type": "pubkey"
+          },
+          {
+            "name": "coTenant",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "duplicateApplication",
-      "msg": "E012: 重複申請"
+      "name": "coTenantsSet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lease",
+            "type": "pubkey"
+          },
+          {
+            "name": "landlord",
+            "type": "pubkey"
+          },
+          {
+            "name": "coTenants",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "sharesBps",
+            "type": {
+              "vec": "u16"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "leaseAlreadyExists",
-      "msg": "E013: 租約已存在"
+      "name": "config",
+      "docs": [
+        "系統配置帳戶"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "管理員公鑰"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "docs": [
+              "待接受的新管理員（兩階段轉移）"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "guardian",
+            "docs": [
+              "緊急暫停守護者公鑰"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "暫停旗標位元遮罩 (PAUSE_*)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "allowExitWhenPaused",
+            "docs": [
+              "全域暫停時是否保持押金釋放路徑開放"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "apiSigner",
+            "docs": [
+              "API 簽名者公鑰"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "arbitrator",
+            "docs": [
+              "仲裁者公鑰"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "feeReceiver",
+            "docs": [
+              "平台費用接收者"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "usdcMint",
+            "docs": [
+              "USDC SPL Token Mint"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "feeRate",
+            "docs": [
+              "費率 (basis points, 100 = 1%)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "pendingFeeRate",
+            "docs": [
+              "排程中的新費率"
+            ],
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "feeRateEffectiveAt",
+            "docs": [
+              "新費率生效時間 (Unix timestamp)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "arrearsTerminationMonths",
+            "docs": [
+              "欠租達此月數時房東可終止租約"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "initialized",
+            "docs": [
+              "是否已初始化"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "paymentNotDue",
-      "msg": "E014: 支付日尚未到期"
+      "name": "configUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "apiSigner",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "arbitrator",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "feeReceiver",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "feeRate",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "feeRateEffectiveAt",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "guardian",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "arrearsTerminationMonths",
+            "type": {
+              "option": "u8"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "leaseEnded",
-      "msg": "E015: 租約已結束"
+      "name": "dispute",
+      "docs": [
+        "爭議帳戶"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lease",
+            "docs": [
+              "關聯的租約"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "initiator",
+            "docs": [
+              "發起人公鑰"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "docs": [
+              "爭議原因: 0=押金爭議, 1=其他"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "狀態: 0=進行中, 1=已解決"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "創建時間戳"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "amountMismatch",
-      "msg": "E016: 金額不匹配"
+      "name": "disputeRaised",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "lease",
+            "type": "pubkey"
+          },
+          {
+            "name": "escrow",
+            "type": "pubkey"
+          },
+          {
+            "name": "initiator",
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "disputeAlreadyResolved",
-      "msg": "E017: 爭議已解決"
+      "name": "disputeResolved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "lease",
+            "type": "pubkey"
+          },
+          {
+            "name": "listing",
+            "type": "pubkey"
+          },
+          {
+            "name": "escrow",
+            "type": "pubkey"
+          },
+          {
+            "name": "arbitrator",
+            "type": "pubkey"
+          },
+          {
+            "name": "landlordAmount",
+            "type": "u64"
+          },
+          {
+            "name": "tenantAmount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "notArbitrator",
-      "msg": "E018: 非仲裁者"
+      "name": "earlyTerminationAccepted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lease",
+            "type": "pubkey"
+          },
+          {
+            "name": "listing",
+            "type": "pubkey"
+          },
+          {
+            "name": "escrow",
+            "type": "pubkey"
+          },
+          {
+            "name": "landlord",
+            "type": "pubkey"
+          },
+          {
+            "name": "tenant",
+            "type": "pubkey"
+          },
+          {
+            "name": "terminationDate",
+            "type": "i64"
+          },
+          {
+            "name": "finalizedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "penalty",
+            "type": "u64"
+          },
+          {
+            "name": "remainingDeposit",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6018,
-      "name": "depositAlreadyReleased",
-      "msg": "E019: 押金已釋放"
+      "name": "earlyTerminationCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lease",
+            "type": "pubkey"
+          },
+          {
+            "name": "cancelledBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "terminationDate",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6019,
-      "name": "invalidDate",
-      "msg": "E020: 無效的日期"
+      "name": "earlyTerminationRequested",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lease",
+            "type": "pubkey"
+          },
+          {
+            "name": "tenant",
+            "type": "pubkey"
+          },
+          {
+            "name": "terminationDate",
+            "type": "i64"
+          },
+          {
+            "name": "requestedAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "invalidFeeRate",
-      "msg": "E021: 無效的費率"
+      "name": "escrow",
+      "docs": [
+        "押金託管帳戶"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lease",
+            "docs": [
+              "關聯的租約"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "押金總額 (USDC lamports)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "狀態: 0=持有中, 1=釋放中, 2=已釋放, 3=凍結待結算"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "releaseToLandlord",
+            "docs": [
+              "分配給房東的金額"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "releaseToTenant",
+            "docs": [
+              "分配給承租人的金額"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "landlordSigned",
+            "docs": [
+              "房東是否確認結算"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "tenantSigned",
+            "docs": [
+              "承租人是否確認結算"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "hasDispute",
+            "docs": [
+              "是否有爭議"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6021,
-      "name": "invalidDepositAmount",
-      "msg": "E022: 無效的押金金額"
+      "name": "guaranteeSigned",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lease",
+            "type": "pubkey"
+          },
+          {
+            "name": "guarantor",
+            "type": "pubkey"
+          },
+          {
+            "name": "guaranteeCap",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6022,
-      "name": "invalidPaymentDay",
-      "msg": "E023: 無效的支付日"
+      "name": "guarantorClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lease",
+            "type": "pubkey"
+          },
+          {
+            "name": "landlord",
+            "type": "pubkey"
+          },
+          {
+            "name": "guarantor",
+            "type": "pubkey"
+          },
+          {
+            "name": "month",
+            "type": "u32"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "platformFee",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6023,
-      "name": "invalidDisputeReason",
-      "msg": "E024: 無效的爭議原因"
+      "name": "guarantorSet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lease",
+            "type": "pubkey"
+          },
+          {
+            "name": "landlord",
+            "type": "pubkey"
+          },
+          {
+            "name": "guarantor",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6024,
-      "name": "cannotApplyOwnListing",
-      "msg": "E025: 無法對自己的房源申請"
-    }
-  ],
-  "types": [
+      "name": "lateFeePolicySet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lease",
+            "type": "pubkey"
+          },
+          {
+            "name": "landlord",
+            "type": "pubkey"
+          },
+          {
+            "name": "graceDays",
+            "type": "u8"
+          },
+          {
+            "name": "lateFeeType",
+            "type": "u8"
+          },
+          {
+            "name": "lateFeeValue",
+            "type": "u64"
+          }
+        ]
+      }
+    },
     {
-      "name": "application",
+      "name": "lease",
       "docs": [
-        "租賃申請帳戶"
+        "租約帳戶"
       ],
       "type": {
         "kind": "struct",
@@ -2047,28 +6373,91 @@ export type Zuvi = {
           {
             "name": "listing",
             "docs": [
-              "申請的房源"
+              "關聯的房源"
             ],
             "type": "pubkey"
           },
           {
-            "name": "applicant",
+            "name": "landlord",
             "docs": [
-              "申請人公鑰"
+              "房東公鑰"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "tenant",
+            "docs": [
+              "承租人公鑰"
             ],
             "type": "pubkey"
           },
           {
             "name": "tenantAttest",
             "docs": [
-              "承租人憑證公鑰"
+              "承租人憑證"
             ],
             "type": "pubkey"
           },
           {
-            "name": "messageUri",
+            "name": "rent",
             "docs": [
-              "IPFS Hash 存放申請資料"
+              "月租金 (USDC lamports)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "deposit",
+            "docs": [
+              "押金金額 (USDC lamports)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "feeRate",
+            "docs": [
+              "簽約時鎖定的平台費率 (basis points)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "startDate",
+            "docs": [
+              "開始日期 (Unix timestamp)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "endDate",
+            "docs": [
+              "結束日期 (Unix timestamp)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "paymentDay",
+            "docs": [
+              "繳費日：月/季/年繳為每月幾號 (1-28)，週繳為星期幾 (1-7)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "paidMonths",
+            "docs": [
+              "已付月數"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastPayment",
+            "docs": [
+              "上次付款時間"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "contractUri",
+            "docs": [
+              "IPFS Hash 存放合約內容"
             ],
             "type": {
               "array": [
@@ -2078,29 +6467,243 @@ export type Zuvi = {
             }
           },
           {
-            "name": "status",
+            "name": "status",
+            "docs": [
+              "狀態: 0=生效中, 1=已完成, 2=已終止"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "landlordSigned",
+            "docs": [
+              "房東是否已簽署"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "tenantSigned",
+            "docs": [
+              "承租人是否已簽署"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "earlyTerminationRequested",
+            "docs": [
+              "承租人是否已申請提前終止"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "terminationDate",
+            "docs": [
+              "提前終止生效日 (Unix timestamp, 0 = 無)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "terminationPenalty",
+            "docs": [
+              "提前終止違約金 (USDC lamports)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "terminationProposer",
+            "docs": [
+              "合意終止提議人"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "terminationRefund",
+            "docs": [
+              "合意終止時自押金退還承租人的租金 (USDC lamports)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "renewalEndDate",
+            "docs": [
+              "續約提議的新結束日期 (Unix timestamp, 0 = 無)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "renewalRent",
+            "docs": [
+              "續約提議的新月租金 (USDC lamports)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "escalationBps",
+            "docs": [
+              "每年調漲幅度 (basis points)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "escalationCapBps",
+            "docs": [
+              "累計調漲上限 (basis points)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "graceDays",
+            "docs": [
+              "繳租寬限天數"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "lateFeeType",
+            "docs": [
+              "滯納金類型: 0=無, 1=固定金額, 2=每日 basis points"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "lateFeeValue",
+            "docs": [
+              "滯納金數值（固定金額或每日 basis points）"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "prepayDiscountBps",
+            "docs": [
+              "預付未到期租金折扣 (basis points)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "paymentFrequency",
+            "docs": [
+              "繳費週期: 0=月繳, 1=週繳, 2=季繳, 3=年繳"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "utcOffsetMinutes",
+            "docs": [
+              "計算到期日所用的當地時區 (相對 UTC 的分鐘數)"
+            ],
+            "type": "i16"
+          },
+          {
+            "name": "autoDebitCap",
+            "docs": [
+              "承租人核准租約 PDA 自動扣款的額度 (USDC lamports, 0 = 未啟用)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "payerRestricted",
+            "docs": [
+              "是否僅允許承租人與指定代繳人支付租金"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "approvedPayers",
+            "docs": [
+              "承租人指定的代繳人（雇主、租金補貼單位等）"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "coTenants",
+            "docs": [
+              "共同承租人（主承租人分攤剩餘比例）"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "coTenant"
+                }
+              }
+            }
+          },
+          {
+            "name": "contributionMask",
+            "docs": [
+              "本期已繳納分攤租金的承租人位元遮罩（bit 0 = 主承租人）"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "periodLandlordFunded",
+            "docs": [
+              "本期已收到的房東分攤款 (USDC lamports)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "periodFeeFunded",
+            "docs": [
+              "本期已收到的平台費分攤款 (USDC lamports)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "periodCharge",
+            "docs": [
+              "首筆分攤款繳納時鎖定的本期租金明細，各承租人依此計算分攤金額"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "rentCharge"
+                }
+              }
+            }
+          },
+          {
+            "name": "guarantor",
+            "docs": [
+              "保證人"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "guarantorSigned",
+            "docs": [
+              "保證人是否已簽署並核准代償額度"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "originalTenant",
             "docs": [
-              "狀態: 0=待審, 1=核准, 2=拒絕"
+              "建立租約時的承租人（租約 PDA 種子，承租人更換錢包後不變）"
             ],
-            "type": "u8"
+            "type": "pubkey"
           },
           {
-            "name": "createdAt",
+            "name": "termsRevision",
             "docs": [
-              "創建時間戳"
+              "簽署前條款的修訂次數，承租人簽署時須與其審閱的版本相符"
             ],
-            "type": "i64"
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "applicationApproved",
+      "name": "leaseCompleted",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "application",
+            "name": "lease",
             "type": "pubkey"
           },
           {
@@ -2108,23 +6711,35 @@ export type Zuvi = {
             "type": "pubkey"
           },
           {
-            "name": "applicant",
+            "name": "landlord",
             "type": "pubkey"
           },
           {
-            "name": "owner",
+            "name": "tenant",
+            "type": "pubkey"
+          },
+          {
+            "name": "paidMonths",
+            "type": "u32"
+          },
+          {
+            "name": "completedBy",
             "type": "pubkey"
+          },
+          {
+            "name": "completedAt",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "applicationCancelled",
+      "name": "leaseCreated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "application",
+            "name": "lease",
             "type": "pubkey"
           },
           {
@@ -2132,23 +6747,39 @@ export type Zuvi = {
             "type": "pubkey"
           },
           {
-            "name": "applicant",
+            "name": "landlord",
             "type": "pubkey"
           },
           {
-            "name": "cancelledBy",
+            "name": "tenant",
             "type": "pubkey"
+          },
+          {
+            "name": "startDate",
+            "type": "i64"
+          },
+          {
+            "name": "endDate",
+            "type": "i64"
+          },
+          {
+            "name": "rent",
+            "type": "u64"
+          },
+          {
+            "name": "deposit",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "applicationClosed",
+      "name": "leaseSigned",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "application",
+            "name": "lease",
             "type": "pubkey"
           },
           {
@@ -2156,43 +6787,35 @@ export type Zuvi = {
             "type": "pubkey"
           },
           {
-            "name": "applicant",
+            "name": "landlord",
             "type": "pubkey"
-          }
-        ]
-      }
-    },
-    {
-      "name": "applicationRejected",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "application",
+            "name": "tenant",
             "type": "pubkey"
           },
           {
-            "name": "listing",
+            "name": "escrow",
             "type": "pubkey"
           },
           {
-            "name": "applicant",
-            "type": "pubkey"
+            "name": "depositAmount",
+            "type": "u64"
           },
           {
-            "name": "owner",
-            "type": "pubkey"
+            "name": "firstRentPaid",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "applicationSubmitted",
+      "name": "leaseTerminatedForArrears",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "application",
+            "name": "lease",
             "type": "pubkey"
           },
           {
@@ -2200,377 +6823,449 @@ export type Zuvi = {
             "type": "pubkey"
           },
           {
-            "name": "applicant",
+            "name": "escrow",
             "type": "pubkey"
           },
           {
-            "name": "tenantAttest",
+            "name": "landlord",
             "type": "pubkey"
           },
           {
-            "name": "createdAt",
+            "name": "tenant",
+            "type": "pubkey"
+          },
+          {
+            "name": "arrearsPeriods",
+            "type": "u32"
+          },
+          {
+            "name": "arrearsAmount",
+            "type": "u64"
+          },
+          {
+            "name": "terminatedAt",
             "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "config",
+      "name": "listing",
       "docs": [
-        "系統配置帳戶"
+        "房源列表帳戶"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "apiSigner",
+            "name": "owner",
             "docs": [
-              "API 簽名者公鑰"
+              "房東公鑰"
             ],
             "type": "pubkey"
           },
           {
-            "name": "arbitrator",
+            "name": "propertyAttest",
             "docs": [
-              "仲裁者公鑰"
+              "產權憑證公鑰"
             ],
             "type": "pubkey"
           },
           {
-            "name": "feeReceiver",
+            "name": "address",
             "docs": [
-              "平台費用接收者"
+              "房屋地址（來自憑證揭露）"
             ],
-            "type": "pubkey"
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
           },
           {
-            "name": "usdcMint",
+            "name": "buildingArea",
             "docs": [
-              "USDC SPL Token Mint"
+              "建物面積（來自憑證揭露）"
             ],
-            "type": "pubkey"
+            "type": "u32"
           },
           {
-            "name": "feeRate",
+            "name": "rent",
             "docs": [
-              "費率 (basis points, 100 = 1%)"
+              "月租金 (USDC lamports)"
             ],
-            "type": "u16"
+            "type": "u64"
           },
           {
-            "name": "initialized",
+            "name": "deposit",
             "docs": [
-              "是否已初始化"
+              "押金金額 (USDC lamports)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "metadataUri",
+            "docs": [
+              "IPFS Hash 存放房源詳情"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          },
+          {
+            "name": "status",
+            "docs": [
+              "狀態: 0=可用, 1=已租, 2=下架"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "currentTenant",
+            "docs": [
+              "當前承租人（如果有）"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "hasActiveLease",
+            "docs": [
+              "是否有生效中的租約"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "hasApprovedApplication",
+            "docs": [
+              "是否有已核准的申請"
             ],
             "type": "bool"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "創建時間戳"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "manager",
+            "docs": [
+              "受託管理人"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "managerPermissions",
+            "docs": [
+              "管理人權限位元遮罩 (MANAGER_PERMISSION_*)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "ownerAttest",
+            "docs": [
+              "現任房東的產權憑證（過戶後更新，property_attest 仍為 PDA 種子）"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "currentLease",
+            "docs": [
+              "目前生效中的租約"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
           }
         ]
       }
     },
     {
-      "name": "dispute",
-      "docs": [
-        "爭議帳戶"
-      ],
+      "name": "listingCreated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "lease",
-            "docs": [
-              "關聯的租約"
-            ],
+            "name": "listing",
             "type": "pubkey"
           },
           {
-            "name": "initiator",
-            "docs": [
-              "發起人公鑰"
-            ],
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "propertyAttest",
+            "type": "pubkey"
+          },
+          {
+            "name": "rent",
+            "type": "u64"
+          },
+          {
+            "name": "deposit",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "listingManagerSet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "listing",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
             "type": "pubkey"
           },
           {
-            "name": "reason",
-            "docs": [
-              "爭議原因: 0=押金爭議, 1=其他"
-            ],
-            "type": "u8"
+            "name": "manager",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "status",
-            "docs": [
-              "狀態: 0=進行中, 1=已解決"
-            ],
+            "name": "permissions",
             "type": "u8"
-          },
-          {
-            "name": "createdAt",
-            "docs": [
-              "創建時間戳"
-            ],
-            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "disputeRaised",
+      "name": "listingOwnershipTransferred",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "dispute",
+            "name": "listing",
             "type": "pubkey"
           },
           {
-            "name": "lease",
+            "name": "previousOwner",
             "type": "pubkey"
           },
           {
-            "name": "escrow",
+            "name": "newOwner",
             "type": "pubkey"
           },
           {
-            "name": "initiator",
+            "name": "ownerAttest",
             "type": "pubkey"
           },
           {
-            "name": "reason",
-            "type": "u8"
-          },
-          {
-            "name": "createdAt",
-            "type": "i64"
+            "name": "lease",
+            "type": {
+              "option": "pubkey"
+            }
           }
         ]
       }
     },
     {
-      "name": "disputeResolved",
+      "name": "listingToggled",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "dispute",
+            "name": "listing",
             "type": "pubkey"
           },
           {
-            "name": "lease",
+            "name": "owner",
             "type": "pubkey"
           },
+          {
+            "name": "newStatus",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "listingUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
             "name": "listing",
             "type": "pubkey"
           },
           {
-            "name": "escrow",
+            "name": "owner",
             "type": "pubkey"
           },
           {
-            "name": "arbitrator",
-            "type": "pubkey"
+            "name": "rent",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "landlordAmount",
-            "type": "u64"
+            "name": "deposit",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "tenantAmount",
-            "type": "u64"
+            "name": "metadataUpdated",
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "escrow",
-      "docs": [
-        "押金託管帳戶"
-      ],
+      "name": "pauseChanged",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "lease",
-            "docs": [
-              "關聯的租約"
-            ],
+            "name": "config",
             "type": "pubkey"
           },
           {
-            "name": "amount",
-            "docs": [
-              "押金總額 (USDC lamports)"
-            ],
-            "type": "u64"
+            "name": "guardian",
+            "type": "pubkey"
           },
           {
-            "name": "status",
-            "docs": [
-              "狀態: 0=持有中, 1=釋放中, 2=已釋放"
-            ],
-            "type": "u8"
+            "name": "paused",
+            "type": "u64"
           },
           {
-            "name": "releaseToLandlord",
-            "docs": [
-              "分配給房東的金額"
-            ],
-            "type": "u64"
+            "name": "allowExitWhenPaused",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "paymentScheduleSet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lease",
+            "type": "pubkey"
           },
           {
-            "name": "releaseToTenant",
-            "docs": [
-              "分配給承租人的金額"
-            ],
-            "type": "u64"
+            "name": "landlord",
+            "type": "pubkey"
           },
           {
-            "name": "landlordSigned",
-            "docs": [
-              "房東是否確認結算"
-            ],
-            "type": "bool"
+            "name": "paymentFrequency",
+            "type": "u8"
           },
           {
-            "name": "tenantSigned",
-            "docs": [
-              "承租人是否確認結算"
-            ],
-            "type": "bool"
+            "name": "paymentDay",
+            "type": "u8"
           },
           {
-            "name": "hasDispute",
-            "docs": [
-              "是否有爭議"
-            ],
-            "type": "bool"
+            "name": "utcOffsetMinutes",
+            "type": "i16"
           }
         ]
       }
     },
     {
-      "name": "lease",
-      "docs": [
-        "租約帳戶"
-      ],
+      "name": "prepayDiscountSet",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "listing",
-            "docs": [
-              "關聯的房源"
-            ],
+            "name": "lease",
             "type": "pubkey"
           },
           {
             "name": "landlord",
-            "docs": [
-              "房東公鑰"
-            ],
             "type": "pubkey"
           },
           {
-            "name": "tenant",
-            "docs": [
-              "承租人公鑰"
-            ],
-            "type": "pubkey"
-          },
+            "name": "discountBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "releaseConfirmed",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "tenantAttest",
-            "docs": [
-              "承租人憑證"
-            ],
+            "name": "escrow",
             "type": "pubkey"
           },
           {
-            "name": "rent",
-            "docs": [
-              "月租金 (USDC lamports)"
-            ],
-            "type": "u64"
+            "name": "lease",
+            "type": "pubkey"
           },
           {
-            "name": "deposit",
-            "docs": [
-              "押金金額 (USDC lamports)"
-            ],
-            "type": "u64"
+            "name": "listing",
+            "type": "pubkey"
           },
           {
-            "name": "startDate",
-            "docs": [
-              "開始日期 (Unix timestamp)"
-            ],
-            "type": "i64"
+            "name": "landlord",
+            "type": "pubkey"
           },
           {
-            "name": "endDate",
-            "docs": [
-              "結束日期 (Unix timestamp)"
-            ],
-            "type": "i64"
+            "name": "tenant",
+            "type": "pubkey"
           },
           {
-            "name": "paymentDay",
-            "docs": [
-              "每月繳費日 (1-28)"
-            ],
-            "type": "u8"
+            "name": "landlordAmount",
+            "type": "u64"
           },
           {
-            "name": "paidMonths",
-            "docs": [
-              "已付月數"
-            ],
-            "type": "u32"
-          },
+            "name": "tenantAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "releaseInitiated",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "lastPayment",
-            "docs": [
-              "上次付款時間"
-            ],
-            "type": "i64"
+            "name": "escrow",
+            "type": "pubkey"
           },
           {
-            "name": "contractUri",
-            "docs": [
-              "IPFS Hash 存放合約內容"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                64
-              ]
-            }
+            "name": "lease",
+            "type": "pubkey"
           },
           {
-            "name": "status",
-            "docs": [
-              "狀態: 0=生效中, 1=已完成, 2=已終止"
-            ],
-            "type": "u8"
+            "name": "initiator",
+            "type": "pubkey"
           },
           {
-            "name": "landlordSigned",
-            "docs": [
-              "房東是否已簽署"
-            ],
-            "type": "bool"
+            "name": "landlordAmount",
+            "type": "u64"
           },
-          {
-            "name": "tenantSigned",
-            "docs": [
-              "承租人是否已簽署"
-            ],
-            "type": "bool"
+          {
+            "name": "tenantAmount",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "leaseCreated",
+      "name": "renewalAccepted",
       "type": {
         "kind": "struct",
         "fields": [
@@ -2579,11 +7274,7 @@ export type Zuvi = {
             "type": "pubkey"
           },
           {
-            "name": "listing",
-            "type": "pubkey"
-          },
-          {
-            "name": "landlord",
+            "name": "escrow",
             "type": "pubkey"
           },
           {
@@ -2591,26 +7282,30 @@ export type Zuvi = {
             "type": "pubkey"
           },
           {
-            "name": "startDate",
+            "name": "previousEndDate",
             "type": "i64"
           },
           {
-            "name": "endDate",
+            "name": "newEndDate",
             "type": "i64"
           },
           {
-            "name": "rent",
+            "name": "newRent",
             "type": "u64"
           },
           {
             "name": "deposit",
             "type": "u64"
+          },
+          {
+            "name": "proratedTopUp",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "leaseSigned",
+      "name": "renewalOffered",
       "type": {
         "kind": "struct",
         "fields": [
@@ -2618,290 +7313,299 @@ export type Zuvi = {
             "name": "lease",
             "type": "pubkey"
           },
-          {
-            "name": "listing",
-            "type": "pubkey"
-          },
           {
             "name": "landlord",
             "type": "pubkey"
           },
           {
-            "name": "tenant",
-            "type": "pubkey"
-          },
-          {
-            "name": "escrow",
-            "type": "pubkey"
-          },
-          {
-            "name": "depositAmount",
-            "type": "u64"
+            "name": "newEndDate",
+            "type": "i64"
           },
           {
-            "name": "firstRentPaid",
+            "name": "newRent",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "listing",
+      "name": "rentCharge",
       "docs": [
-        "房源列表帳戶"
+        "單期租金明細"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "owner",
+            "name": "month",
             "docs": [
-              "房東公鑰"
+              "期數（1 起算）"
             ],
-            "type": "pubkey"
+            "type": "u32"
           },
           {
-            "name": "propertyAttest",
+            "name": "rentAmount",
             "docs": [
-              "產權憑證公鑰"
+              "當期租金（已扣除預付折扣）"
             ],
-            "type": "pubkey"
+            "type": "u64"
           },
           {
-            "name": "address",
+            "name": "discount",
             "docs": [
-              "房屋地址（來自憑證揭露）"
+              "預付折扣"
             ],
-            "type": {
-              "array": [
-                "u8",
-                64
-              ]
-            }
+            "type": "u64"
           },
           {
-            "name": "buildingArea",
+            "name": "platformFee",
             "docs": [
-              "建物面積（來自憑證揭露）"
+              "平台費"
             ],
-            "type": "u32"
+            "type": "u64"
           },
           {
-            "name": "rent",
+            "name": "lateFee",
             "docs": [
-              "月租金 (USDC lamports)"
+              "滯納金（全額歸房東）"
             ],
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rentContributed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lease",
+            "type": "pubkey"
           },
           {
-            "name": "deposit",
-            "docs": [
-              "押金金額 (USDC lamports)"
-            ],
-            "type": "u64"
+            "name": "contributor",
+            "type": "pubkey"
           },
           {
-            "name": "metadataUri",
-            "docs": [
-              "IPFS Hash 存放房源詳情"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                64
-              ]
-            }
+            "name": "tenant",
+            "type": "pubkey"
           },
           {
-            "name": "status",
-            "docs": [
-              "狀態: 0=可用, 1=已租, 2=下架"
-            ],
-            "type": "u8"
+            "name": "month",
+            "type": "u32"
           },
           {
-            "name": "currentTenant",
-            "docs": [
-              "當前承租人（如果有）"
-            ],
-            "type": {
-              "option": "pubkey"
-            }
+            "name": "landlordAmount",
+            "type": "u64"
           },
           {
-            "name": "hasActiveLease",
-            "docs": [
-              "是否有生效中的租約"
-            ],
-            "type": "bool"
+            "name": "platformFee",
+            "type": "u64"
           },
           {
-            "name": "hasApprovedApplication",
-            "docs": [
-              "是否有已核准的申請"
-            ],
+            "name": "fullyFunded",
             "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rentEscalationSet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lease",
+            "type": "pubkey"
           },
           {
-            "name": "createdAt",
-            "docs": [
-              "創建時間戳"
-            ],
-            "type": "i64"
+            "name": "landlord",
+            "type": "pubkey"
+          },
+          {
+            "name": "escalationBps",
+            "type": "u16"
+          },
+          {
+            "name": "escalationCapBps",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "listingCreated",
+      "name": "rentPaid",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "listing",
+            "name": "lease",
             "type": "pubkey"
           },
           {
-            "name": "owner",
+            "name": "tenant",
             "type": "pubkey"
           },
           {
-            "name": "propertyAttest",
+            "name": "payer",
             "type": "pubkey"
           },
           {
-            "name": "rent",
+            "name": "landlord",
+            "type": "pubkey"
+          },
+          {
+            "name": "month",
+            "type": "u32"
+          },
+          {
+            "name": "rentAmount",
             "type": "u64"
           },
           {
-            "name": "deposit",
+            "name": "discount",
             "type": "u64"
           },
           {
-            "name": "createdAt",
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "platformFee",
+            "type": "u64"
+          },
+          {
+            "name": "lateFee",
+            "type": "u64"
+          },
+          {
+            "name": "paymentDate",
             "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "listingToggled",
+      "name": "rentPayersSet",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "listing",
+            "name": "lease",
             "type": "pubkey"
           },
           {
-            "name": "owner",
+            "name": "tenant",
             "type": "pubkey"
           },
           {
-            "name": "newStatus",
-            "type": "u8"
+            "name": "payerRestricted",
+            "type": "bool"
+          },
+          {
+            "name": "approvedPayers",
+            "type": {
+              "vec": "pubkey"
+            }
           }
         ]
       }
     },
     {
-      "name": "listingUpdated",
+      "name": "tenantMigrated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "listing",
+            "name": "lease",
             "type": "pubkey"
           },
           {
-            "name": "owner",
+            "name": "listing",
             "type": "pubkey"
           },
           {
-            "name": "rent",
-            "type": {
-              "option": "u64"
-            }
+            "name": "oldTenant",
+            "type": "pubkey"
           },
           {
-            "name": "deposit",
-            "type": {
-              "option": "u64"
-            }
+            "name": "newTenant",
+            "type": "pubkey"
           },
           {
-            "name": "metadataUpdated",
-            "type": "bool"
+            "name": "tenantAttest",
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "releaseConfirmed",
+      "name": "terminationCancelled",
       "type": {
         "kind": "struct",
         "fields": [
-          {
-            "name": "escrow",
-            "type": "pubkey"
-          },
           {
             "name": "lease",
             "type": "pubkey"
           },
           {
-            "name": "listing",
-            "type": "pubkey"
-          },
-          {
-            "name": "landlord",
+            "name": "proposer",
             "type": "pubkey"
           },
           {
-            "name": "tenant",
+            "name": "cancelledBy",
             "type": "pubkey"
           },
           {
-            "name": "landlordAmount",
-            "type": "u64"
-          },
-          {
-            "name": "tenantAmount",
-            "type": "u64"
+            "name": "terminationDate",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "releaseInitiated",
+      "name": "terminationConfirmed",
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "lease",
+            "type": "pubkey"
+          },
+          {
+            "name": "listing",
+            "type": "pubkey"
+          },
           {
             "name": "escrow",
             "type": "pubkey"
           },
           {
-            "name": "lease",
+            "name": "landlord",
             "type": "pubkey"
           },
           {
-            "name": "initiator",
+            "name": "tenant",
             "type": "pubkey"
           },
           {
-            "name": "landlordAmount",
+            "name": "terminationDate",
+            "type": "i64"
+          },
+          {
+            "name": "refundAmount",
             "type": "u64"
           },
           {
-            "name": "tenantAmount",
+            "name": "remainingDeposit",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "rentPaid",
+      "name": "terminationProposed",
       "type": {
         "kind": "struct",
         "fields": [
@@ -2910,28 +7614,16 @@ export type Zuvi = {
             "type": "pubkey"
           },
           {
-            "name": "tenant",
-            "type": "pubkey"
-          },
-          {
-            "name": "landlord",
+            "name": "proposer",
             "type": "pubkey"
           },
           {
-            "name": "month",
-            "type": "u32"
-          },
-          {
-            "name": "amount",
-            "type": "u64"
+            "name": "terminationDate",
+            "type": "i64"
           },
           {
-            "name": "platformFee",
+            "name": "refundAmount",
             "type": "u64"
-          },
-          {
-            "name": "paymentDate",
-            "type": "i64"
           }
         ]
       }
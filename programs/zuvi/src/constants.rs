@@ -7,10 +7,12 @@ pub const ESCROW_SEED: &[u8] = b"escrow";
 pub const DISPUTE_SEED: &[u8] = b"dispute";
 
 /// 帳戶大小常數
-pub const CONFIG_SIZE: usize = 8 + 32 + 32 + 32 + 32 + 2 + 1; // ~139 bytes
-pub const LISTING_SIZE: usize = 8 + 32 + 32 + 64 + 4 + 8 + 8 + 64 + 1 + 1 + 32 + 1 + 1 + 8; // ~287 bytes
+pub const CONFIG_SIZE: usize = 8 + 32 + 33 + 32 + 8 + 1 + 32 + 32 + 32 + 32 + 2 + 3 + 8 + 1 + 1; // ~257 bytes
+pub const LISTING_SIZE: usize = 8 + 32 + 32 + 64 + 4 + 8 + 8 + 64 + 1 + 1 + 32 + 1 + 1 + 8 + 33 + 1 + 32 + 33; // ~386 bytes
 pub const APPLICATION_SIZE: usize = 8 + 32 + 32 + 32 + 64 + 1 + 8; // ~225 bytes
-pub const LEASE_SIZE: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + 4 + 8 + 64 + 1 + 1 + 1; // ~293 bytes
+pub const LEASE_SIZE: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 2 + 8 + 8 + 1 + 4 + 8 + 64 + 1 + 1 + 1 + 1 + 8 + 8 + 33 + 8 + 8 + 8 + 2 + 2 + 1 + 1 + 8 + 2 + 1 + 2 + 8 + 1 + (4 + 32 * MAX_APPROVED_PAYERS) + (4 + CO_TENANT_SIZE * MAX_CO_TENANTS) + 1 + 8 + 8 + (1 + RENT_CHARGE_SIZE) + 33 + 1 + 32 + 4; // ~765 bytes
+pub const CO_TENANT_SIZE: usize = 32 + 2 + 1;
+pub const RENT_CHARGE_SIZE: usize = 4 + 8 + 8 + 8 + 8;
 pub const ESCROW_SIZE: usize = 8 + 32 + 8 + 1 + 8 + 8 + 1 + 1 + 1; // ~115 bytes
 pub const DISPUTE_SIZE: usize = 8 + 32 + 32 + 1 + 1 + 8; // ~75 bytes
 
@@ -27,9 +29,15 @@ pub const LEASE_STATUS_ACTIVE: u8 = 0;
 pub const LEASE_STATUS_COMPLETED: u8 = 1;
 pub const LEASE_STATUS_TERMINATED: u8 = 2;
 
+pub const PAYMENT_FREQUENCY_MONTHLY: u8 = 0;
+pub const PAYMENT_FREQUENCY_WEEKLY: u8 = 1;
+pub const PAYMENT_FREQUENCY_QUARTERLY: u8 = 2;
+pub const PAYMENT_FREQUENCY_YEARLY: u8 = 3;
+
 pub const ESCROW_STATUS_HOLDING: u8 = 0;
 pub const ESCROW_STATUS_RELEASING: u8 = 1;
 pub const ESCROW_STATUS_RELEASED: u8 = 2;
+pub const ESCROW_STATUS_FROZEN: u8 = 3;
 
 pub const DISPUTE_STATUS_OPEN: u8 = 0;
 pub const DISPUTE_STATUS_RESOLVED: u8 = 1;
@@ -37,16 +45,97 @@ pub const DISPUTE_STATUS_RESOLVED: u8 = 1;
 pub const DISPUTE_REASON_DEPOSIT: u8 = 0;
 pub const DISPUTE_REASON_OTHER: u8 = 1;
 
+pub const LATE_FEE_TYPE_NONE: u8 = 0;
+pub const LATE_FEE_TYPE_FLAT: u8 = 1;
+pub const LATE_FEE_TYPE_DAILY_BPS: u8 = 2;
+
+/// 房源管理人權限（Listing.manager_permissions 位元遮罩）
+pub const MANAGER_PERMISSION_APPROVE_APPLICATION: u8 = 1 << 0;
+pub const MANAGER_PERMISSION_CREATE_LEASE: u8 = 1 << 1;
+pub const MANAGER_PERMISSION_TOGGLE_LISTING: u8 = 1 << 2;
+pub const MANAGER_PERMISSION_UPDATE_LISTING: u8 = 1 << 3;
+pub const MANAGER_PERMISSION_ALL: u8 = MANAGER_PERMISSION_APPROVE_APPLICATION
+    | MANAGER_PERMISSION_CREATE_LEASE
+    | MANAGER_PERMISSION_TOGGLE_LISTING
+    | MANAGER_PERMISSION_UPDATE_LISTING;
+
+/// 暫停旗標（Config.paused 位元遮罩）
+pub const PAUSE_CREATE_LISTING: u64 = 1 << 0;
+pub const PAUSE_UPDATE_LISTING: u64 = 1 << 1;
+pub const PAUSE_TOGGLE_LISTING: u64 = 1 << 2;
+pub const PAUSE_APPLY_LEASE: u64 = 1 << 3;
+pub const PAUSE_CLOSE_APPLICATION: u64 = 1 << 4;
+pub const PAUSE_CANCEL_APPROVED_APPLICATION: u64 = 1 << 5;
+pub const PAUSE_APPROVE_APPLICATION: u64 = 1 << 6;
+pub const PAUSE_REJECT_APPLICATION: u64 = 1 << 7;
+pub const PAUSE_CREATE_LEASE: u64 = 1 << 8;
+pub const PAUSE_SIGN_LEASE: u64 = 1 << 9;
+pub const PAUSE_PAY_RENT: u64 = 1 << 10;
+pub const PAUSE_INITIATE_RELEASE: u64 = 1 << 11;
+pub const PAUSE_CONFIRM_RELEASE: u64 = 1 << 12;
+pub const PAUSE_RAISE_DISPUTE: u64 = 1 << 13;
+pub const PAUSE_RESOLVE_DISPUTE: u64 = 1 << 14;
+pub const PAUSE_COMPLETE_LEASE: u64 = 1 << 15;
+pub const PAUSE_REQUEST_EARLY_TERMINATION: u64 = 1 << 16;
+pub const PAUSE_ACCEPT_EARLY_TERMINATION: u64 = 1 << 17;
+pub const PAUSE_TERMINATE_FOR_ARREARS: u64 = 1 << 18;
+pub const PAUSE_PROPOSE_TERMINATION: u64 = 1 << 19;
+pub const PAUSE_CONFIRM_TERMINATION: u64 = 1 << 20;
+pub const PAUSE_OFFER_RENEWAL: u64 = 1 << 21;
+pub const PAUSE_ACCEPT_RENEWAL: u64 = 1 << 22;
+pub const PAUSE_SET_RENT_ESCALATION: u64 = 1 << 23;
+pub const PAUSE_SET_LATE_FEE_POLICY: u64 = 1 << 24;
+pub const PAUSE_PREPAY_RENT: u64 = 1 << 25;
+pub const PAUSE_SET_PREPAY_DISCOUNT: u64 = 1 << 26;
+pub const PAUSE_SET_PAYMENT_SCHEDULE: u64 = 1 << 27;
+pub const PAUSE_COLLECT_RENT: u64 = 1 << 28;
+pub const PAUSE_SET_RENT_PAYERS: u64 = 1 << 29;
+pub const PAUSE_SET_CO_TENANTS: u64 = 1 << 30;
+pub const PAUSE_SIGN_LEASE_CO_TENANT: u64 = 1 << 31;
+pub const PAUSE_SET_GUARANTOR: u64 = 1 << 32;
+pub const PAUSE_SIGN_GUARANTEE: u64 = 1 << 33;
+pub const PAUSE_CLAIM_FROM_GUARANTOR: u64 = 1 << 34;
+pub const PAUSE_SET_LISTING_MANAGER: u64 = 1 << 35;
+pub const PAUSE_TRANSFER_LISTING_OWNERSHIP: u64 = 1 << 36;
+pub const PAUSE_MIGRATE_TENANT: u64 = 1 << 37;
+pub const PAUSE_PAY_RENT_SHARE: u64 = 1 << 38;
+pub const PAUSE_CANCEL_EARLY_TERMINATION: u64 = 1 << 39;
+pub const PAUSE_CANCEL_TERMINATION: u64 = 1 << 40;
+pub const PAUSE_ENABLE_AUTO_DEBIT: u64 = 1 << 41;
+pub const PAUSE_ALL: u64 = 1 << 63;
+/// 押金釋放路徑，可設定在全域暫停時保持開放
+pub const PAUSE_EXIT_PATHS: u64 = PAUSE_INITIATE_RELEASE | PAUSE_CONFIRM_RELEASE | PAUSE_RESOLVE_DISPUTE;
+
 /// 業務邏輯常數
 pub const MAX_FEE_RATE: u16 = 1000; // 10% = 1000 basis points
+pub const MIN_FEE_RATE_DELAY_DAYS: i64 = 7; // 費率變更至少延遲 7 天生效
 pub const MIN_DEPOSIT_MONTHS: u8 = 1; // 最少 1 個月押金
 pub const MAX_DEPOSIT_MONTHS: u8 = 3; // 最多 3 個月押金
 pub const MAX_ADVANCE_DAYS: i64 = 30; // 最多提前 30 天
 pub const MIN_PAYMENT_DAY: u8 = 1;
 pub const MAX_PAYMENT_DAY: u8 = 28;
+pub const MAX_WEEKLY_PAYMENT_DAY: u8 = 7; // 週繳時以星期幾作為繳費日
+pub const DEFAULT_UTC_OFFSET_MINUTES: i16 = 480; // 預設台灣時間 UTC+8
+pub const MIN_UTC_OFFSET_MINUTES: i16 = -720; // UTC-12
+pub const MAX_UTC_OFFSET_MINUTES: i16 = 840; // UTC+14
+pub const EARLY_TERMINATION_NOTICE_DAYS: i64 = 30; // 提前終止至少 30 天前通知
+pub const MAX_EARLY_TERMINATION_PENALTY_MONTHS: u64 = 1; // 違約金最多 1 個月租金
+pub const DEFAULT_ARREARS_TERMINATION_MONTHS: u8 = 2; // 欠租達 2 個月可終止租約
+pub const MAX_RENT_ESCALATION_BPS: u16 = 1000; // 每次調漲最多 10%
+pub const MAX_ESCALATION_CAP_BPS: u16 = 5000; // 累計調漲最多 50%
+pub const MAX_GRACE_DAYS: u8 = 27; // 寬限期不得跨越下一期
+pub const MAX_WEEKLY_GRACE_DAYS: u8 = 6; // 週繳寬限期不得跨越下一期
+pub const LATE_FEE_CAP_BPS: u64 = 1000; // 滯納金上限為當期租金 10%
+pub const MAX_RENT_MONTHS_PER_PAYMENT: u32 = 12; // 單筆最多支付 12 期
+pub const MAX_PREPAY_DISCOUNT_BPS: u16 = 2000; // 預付折扣最多 20%
+pub const MAX_APPROVED_PAYERS: usize = 3; // 承租人最多指定 3 個代繳人
+pub const MAX_CO_TENANTS: usize = 4; // 主承租人以外最多 4 位共同承租人
 
 /// 時間常數
 pub const SECONDS_PER_DAY: i64 = 86400;
+pub const MIN_SUPPORTED_YEAR: i32 = 1970;
+pub const MAX_SUPPORTED_YEAR: i32 = 9999;
+pub const MAX_SUPPORTED_TIMESTAMP: i64 = 253_402_300_799; // 9999-12-31 23:59:59 UTC
 
 /// IPFS Hash 長度 (CIDv0)
 pub const IPFS_HASH_LENGTH: usize = 64;
\ No newline at end of file
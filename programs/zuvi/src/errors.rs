@@ -76,4 +76,82 @@ pub enum ZuviError {
     
     #[msg("E025: 無法對自己的房源申請")]
     CannotApplyOwnListing,
+    
+    #[msg("E026: 非管理員")]
+    NotAdmin,
+    
+    #[msg("E027: 沒有待接受的管理員轉移")]
+    NoPendingAdminTransfer,
+    
+    #[msg("E028: 程式已暫停")]
+    ProgramPaused,
+    
+    #[msg("E029: 非守護者")]
+    NotGuardian,
+    
+    #[msg("E030: 租約尚未到期")]
+    LeaseNotEnded,
+    
+    #[msg("E031: 尚有未繳租金")]
+    RentOutstanding,
+    
+    #[msg("E032: 已有待處理的終止申請")]
+    TerminationAlreadyRequested,
+    
+    #[msg("E033: 沒有待處理的終止申請")]
+    NoTerminationRequest,
+    
+    #[msg("E034: 違約金超過上限")]
+    InvalidPenalty,
+    
+    #[msg("E035: 欠租未達終止門檻")]
+    ArrearsBelowThreshold,
+    
+    #[msg("E036: 沒有待接受的續約")]
+    NoRenewalOffer,
+    
+    #[msg("E037: 預付期數超過租期")]
+    PrepaymentExceedsLease,
+    
+    #[msg("E038: 無效的月份")]
+    InvalidMonth,
+    
+    #[msg("E039: 無效的日")]
+    InvalidDayOfMonth,
+    
+    #[msg("E040: 日期超出支援範圍")]
+    DateOutOfRange,
+    
+    #[msg("E041: 未啟用自動扣款")]
+    AutoDebitNotEnabled,
+    
+    #[msg("E042: 自動扣款額度不足")]
+    AutoDebitAllowanceExceeded,
+    
+    #[msg("E043: 非承租人指定的代繳人")]
+    PayerNotApproved,
+    
+    #[msg("E044: 共同承租人尚未全部簽署")]
+    CoTenantsNotSigned,
+    
+    #[msg("E045: 本期分攤租金已繳納")]
+    AlreadyContributed,
+    
+    #[msg("E046: 非共同承租人")]
+    NotCoTenant,
+    
+    #[msg("E047: 本期已有部分分攤款項")]
+    PartialContributionPending,
+    
+    #[msg("E048: 租約未設定保證人")]
+    NoGuarantor,
+    
+    #[msg("E049: 保證人尚未簽署")]
+    GuarantorNotSigned,
+    
+    #[msg("E050: 承租人未逾期欠租")]
+    NotInArrears,
+    
+    #[msg("E051: 租約條款已變更")]
+    TermsChanged,
 }
\ No newline at end of file
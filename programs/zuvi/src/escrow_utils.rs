@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use crate::constants::*;
+
+pub struct EscrowUtils;
+
+impl EscrowUtils {
+    /// 以押金託管帳戶 PDA 簽名，從託管 Token 帳戶轉出
+    pub fn transfer_from_escrow<'info>(
+        token_program: AccountInfo<'info>,
+        escrow_token: AccountInfo<'info>,
+        to: AccountInfo<'info>,
+        escrow: AccountInfo<'info>,
+        lease_key: Pubkey,
+        escrow_bump: u8,
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let escrow_seeds = &[
+            ESCROW_SEED,
+            lease_key.as_ref(),
+            &[escrow_bump],
+        ];
+        let signer_seeds = &[&escrow_seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program,
+                Transfer {
+                    from: escrow_token,
+                    to,
+                    authority: escrow,
+                },
+                signer_seeds,
+            ),
+            amount,
+        )
+    }
+}
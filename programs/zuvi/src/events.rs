@@ -1,5 +1,47 @@
 use anchor_lang::prelude::*;
 
+#[event]
+pub struct ConfigUpdated {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub api_signer: Option<Pubkey>,
+    pub arbitrator: Option<Pubkey>,
+    pub fee_receiver: Option<Pubkey>,
+    pub fee_rate: Option<u16>,
+    pub fee_rate_effective_at: Option<i64>,
+    pub guardian: Option<Pubkey>,
+    pub arrears_termination_months: Option<u8>,
+}
+
+#[event]
+pub struct PauseChanged {
+    pub config: Pubkey,
+    pub guardian: Pubkey,
+    pub paused: u64,
+    pub allow_exit_when_paused: bool,
+}
+
+#[event]
+pub struct AdminTransferProposed {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferAccepted {
+    pub config: Pubkey,
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferCancelled {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub cancelled_admin: Pubkey,
+}
+
 #[event]
 pub struct ListingCreated {
     pub listing: Pubkey,
@@ -26,6 +68,23 @@ pub struct ListingToggled {
     pub new_status: u8,
 }
 
+#[event]
+pub struct ListingManagerSet {
+    pub listing: Pubkey,
+    pub owner: Pubkey,
+    pub manager: Option<Pubkey>,
+    pub permissions: u8,
+}
+
+#[event]
+pub struct ListingOwnershipTransferred {
+    pub listing: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub owner_attest: Pubkey,
+    pub lease: Option<Pubkey>,
+}
+
 #[event]
 pub struct ApplicationSubmitted {
     pub application: Pubkey,
@@ -78,6 +137,13 @@ pub struct LeaseCreated {
     pub deposit: u64,
 }
 
+#[event]
+pub struct AutoDebitSet {
+    pub lease: Pubkey,
+    pub tenant: Pubkey,
+    pub auto_debit_cap: u64,
+}
+
 #[event]
 pub struct LeaseSigned {
     pub lease: Pubkey,
@@ -89,17 +155,219 @@ pub struct LeaseSigned {
     pub first_rent_paid: u64,
 }
 
+#[event]
+pub struct RentEscalationSet {
+    pub lease: Pubkey,
+    pub landlord: Pubkey,
+    pub escalation_bps: u16,
+    pub escalation_cap_bps: u16,
+}
+
+#[event]
+pub struct LateFeePolicySet {
+    pub lease: Pubkey,
+    pub landlord: Pubkey,
+    pub grace_days: u8,
+    pub late_fee_type: u8,
+    pub late_fee_value: u64,
+}
+
+#[event]
+pub struct PaymentScheduleSet {
+    pub lease: Pubkey,
+    pub landlord: Pubkey,
+    pub payment_frequency: u8,
+    pub payment_day: u8,
+    pub utc_offset_minutes: i16,
+}
+
+#[event]
+pub struct RentPayersSet {
+    pub lease: Pubkey,
+    pub tenant: Pubkey,
+    pub payer_restricted: bool,
+    pub approved_payers: Vec<Pubkey>,
+}
+
+#[event]
+pub struct CoTenantsSet {
+    pub lease: Pubkey,
+    pub landlord: Pubkey,
+    pub co_tenants: Vec<Pubkey>,
+    pub shares_bps: Vec<u16>,
+}
+
+#[event]
+pub struct CoTenantSigned {
+    pub lease: Pubkey,
+    pub co_tenant: Pubkey,
+}
+
+#[event]
+pub struct RentContributed {
+    pub lease: Pubkey,
+    pub contributor: Pubkey,
+    pub tenant: Pubkey,
+    pub month: u32,
+    pub landlord_amount: u64,
+    pub platform_fee: u64,
+    pub fully_funded: bool,
+}
+
+#[event]
+pub struct GuarantorSet {
+    pub lease: Pubkey,
+    pub landlord: Pubkey,
+    pub guarantor: Option<Pubkey>,
+}
+
+#[event]
+pub struct GuaranteeSigned {
+    pub lease: Pubkey,
+    pub guarantor: Pubkey,
+    pub guarantee_cap: u64,
+}
+
+#[event]
+pub struct GuarantorClaimed {
+    pub lease: Pubkey,
+    pub landlord: Pubkey,
+    pub guarantor: Pubkey,
+    pub month: u32,
+    pub amount: u64,
+    pub platform_fee: u64,
+}
+
+#[event]
+pub struct TenantMigrated {
+    pub lease: Pubkey,
+    pub listing: Pubkey,
+    pub old_tenant: Pubkey,
+    pub new_tenant: Pubkey,
+    pub tenant_attest: Pubkey,
+}
+
+#[event]
+pub struct PrepayDiscountSet {
+    pub lease: Pubkey,
+    pub landlord: Pubkey,
+    pub discount_bps: u16,
+}
+
 #[event]
 pub struct RentPaid {
     pub lease: Pubkey,
     pub tenant: Pubkey,
+    pub payer: Pubkey,
     pub landlord: Pubkey,
     pub month: u32,
+    pub rent_amount: u64,
+    pub discount: u64,
     pub amount: u64,
     pub platform_fee: u64,
+    pub late_fee: u64,
     pub payment_date: i64,
 }
 
+#[event]
+pub struct LeaseCompleted {
+    pub lease: Pubkey,
+    pub listing: Pubkey,
+    pub landlord: Pubkey,
+    pub tenant: Pubkey,
+    pub paid_months: u32,
+    pub completed_by: Pubkey,
+    pub completed_at: i64,
+}
+
+#[event]
+pub struct EarlyTerminationRequested {
+    pub lease: Pubkey,
+    pub tenant: Pubkey,
+    pub termination_date: i64,
+    pub requested_at: i64,
+}
+
+#[event]
+pub struct EarlyTerminationCancelled {
+    pub lease: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub termination_date: i64,
+}
+
+#[event]
+pub struct EarlyTerminationAccepted {
+    pub lease: Pubkey,
+    pub listing: Pubkey,
+    pub escrow: Pubkey,
+    pub landlord: Pubkey,
+    pub tenant: Pubkey,
+    pub termination_date: i64,
+    pub finalized_by: Pubkey,
+    pub penalty: u64,
+    pub remaining_deposit: u64,
+}
+
+#[event]
+pub struct LeaseTerminatedForArrears {
+    pub lease: Pubkey,
+    pub listing: Pubkey,
+    pub escrow: Pubkey,
+    pub landlord: Pubkey,
+    pub tenant: Pubkey,
+    pub arrears_periods: u32,
+    pub arrears_amount: u64,
+    pub terminated_at: i64,
+}
+
+#[event]
+pub struct TerminationProposed {
+    pub lease: Pubkey,
+    pub proposer: Pubkey,
+    pub termination_date: i64,
+    pub refund_amount: u64,
+}
+
+#[event]
+pub struct TerminationCancelled {
+    pub lease: Pubkey,
+    pub proposer: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub termination_date: i64,
+}
+
+#[event]
+pub struct TerminationConfirmed {
+    pub lease: Pubkey,
+    pub listing: Pubkey,
+    pub escrow: Pubkey,
+    pub landlord: Pubkey,
+    pub tenant: Pubkey,
+    pub termination_date: i64,
+    pub refund_amount: u64,
+    pub remaining_deposit: u64,
+}
+
+#[event]
+pub struct RenewalOffered {
+    pub lease: Pubkey,
+    pub landlord: Pubkey,
+    pub new_end_date: i64,
+    pub new_rent: u64,
+}
+
+#[event]
+pub struct RenewalAccepted {
+    pub lease: Pubkey,
+    pub escrow: Pubkey,
+    pub tenant: Pubkey,
+    pub previous_end_date: i64,
+    pub new_end_date: i64,
+    pub new_rent: u64,
+    pub deposit: u64,
+    pub prorated_top_up: u64,
+}
+
 #[event]
 pub struct ReleaseInitiated {
     pub escrow: Pubkey,
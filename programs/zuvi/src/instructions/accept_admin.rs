@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 新管理員接受管理員權限
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let new_admin = ctx.accounts.new_admin.key();
+    
+    // 確認有待接受的轉移
+    let pending_admin = config.pending_admin.ok_or(ZuviError::NoPendingAdminTransfer)?;
+    
+    // 確認是被提議的新管理員
+    require!(
+        pending_admin == new_admin,
+        ZuviError::Unauthorized
+    );
+    
+    let previous_admin = config.admin;
+    config.admin = new_admin;
+    config.pending_admin = None;
+    
+    emit!(AdminTransferAccepted {
+        config: config.key(),
+        previous_admin,
+        new_admin,
+    });
+    
+    msg!("管理員轉移完成");
+    msg!("原管理員: {}", previous_admin);
+    msg!("新管理員: {}", new_admin);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// 系統配置帳戶
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// 待接受的新管理員
+    pub new_admin: Signer<'info>,
+}
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::TokenAccount;
+use crate::{constants::*, errors::*, escrow_utils::EscrowUtils, events::EarlyTerminationAccepted, state::*};
+
+/// 終止日後結算提前終止：房東可指定不超過上限的違約金，其他人（含承租人）結算時違約金以上限計
+pub fn accept_early_termination(ctx: Context<AcceptEarlyTermination>, penalty: Option<u64>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let listing = &mut ctx.accounts.listing;
+    let lease = &mut ctx.accounts.lease;
+    let escrow = &mut ctx.accounts.escrow;
+    let clock = Clock::get()?;
+    
+    require!(
+        !config.is_paused(PAUSE_ACCEPT_EARLY_TERMINATION),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    require!(
+        lease.early_termination_requested,
+        ZuviError::NoTerminationRequest
+    );
+    
+    require!(
+        clock.unix_timestamp >= lease.termination_date,
+        ZuviError::LeaseNotEnded
+    );
+    
+    require!(
+        escrow.status == ESCROW_STATUS_HOLDING,
+        ZuviError::DepositAlreadyReleased
+    );
+    
+    require!(
+        !escrow.has_dispute,
+        ZuviError::DisputeInProgress
+    );
+    
+    // 終止日前的租金須已付清
+    let payments_due = lease.total_payments_until(lease.termination_date)?;
+    require!(
+        lease.paid_months >= payments_due,
+        ZuviError::RentOutstanding
+    );
+    
+    let max_penalty = lease.rent.checked_mul(MAX_EARLY_TERMINATION_PENALTY_MONTHS).unwrap();
+    let penalty = match penalty {
+        Some(penalty) => {
+            // 僅房東可調降違約金
+            require!(
+                ctx.accounts.signer.key() == lease.landlord,
+                ZuviError::Unauthorized
+            );
+            require!(
+                penalty <= max_penalty,
+                ZuviError::InvalidPenalty
+            );
+            penalty
+        }
+        None => max_penalty.min(escrow.amount),
+    };
+    
+    require!(
+        penalty <= escrow.amount,
+        ZuviError::AmountMismatch
+    );
+    lease.termination_penalty = penalty;
+    
+    EscrowUtils::transfer_from_escrow(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_token.to_account_info(),
+        ctx.accounts.landlord_token.to_account_info(),
+        escrow.to_account_info(),
+        lease.key(),
+        ctx.bumps.escrow,
+        lease.termination_penalty,
+    )?;
+    
+    escrow.amount -= lease.termination_penalty;
+    lease.early_termination_requested = false;
+    lease.status = LEASE_STATUS_TERMINATED;
+    listing.release_lease(&lease.key());
+    
+    emit!(EarlyTerminationAccepted {
+        lease: lease.key(),
+        listing: listing.key(),
+        escrow: escrow.key(),
+        landlord: lease.landlord,
+        tenant: lease.tenant,
+        termination_date: lease.termination_date,
+        finalized_by: ctx.accounts.signer.key(),
+        penalty: lease.termination_penalty,
+        remaining_deposit: escrow.amount,
+    });
+    
+    msg!("租約已提前終止");
+    msg!("違約金: {} USDC", lease.termination_penalty);
+    msg!("剩餘押金: {} USDC", escrow.amount);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptEarlyTermination<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        mut,
+        seeds = [LISTING_SEED, listing.property_attest.as_ref()],
+        bump
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump,
+        constraint = lease.listing == listing.key()
+    )]
+    pub lease: Box<Account<'info, Lease>>,
+    
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, lease.key().as_ref()],
+        bump
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    
+    pub signer: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = escrow_token.owner == escrow.key(),
+        constraint = escrow_token.mint == config.usdc_mint
+    )]
+    pub escrow_token: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = landlord_token.owner == lease.landlord,
+        constraint = landlord_token.mint == config.usdc_mint
+    )]
+    pub landlord_token: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
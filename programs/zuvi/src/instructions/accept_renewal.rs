@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, Transfer};
+use anchor_spl::token_interface::TokenAccount;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 承租人接受續約，延長原租約並沿用押金
+/// 原末期若按日計算至原結束日，同時補收延長部分的租金差額
+pub fn accept_renewal(ctx: Context<AcceptRenewal>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    let escrow = &ctx.accounts.escrow;
+    
+    require!(
+        !config.is_paused(PAUSE_ACCEPT_RENEWAL),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.tenant == ctx.accounts.tenant.key(),
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    require!(
+        lease.renewal_end_date > 0,
+        ZuviError::NoRenewalOffer
+    );
+    
+    require!(
+        !lease.early_termination_requested && lease.termination_proposer.is_none(),
+        ZuviError::TerminationAlreadyRequested
+    );
+    
+    require!(
+        escrow.status == ESCROW_STATUS_HOLDING,
+        ZuviError::DepositAlreadyReleased
+    );
+    
+    require!(
+        !escrow.has_dispute,
+        ZuviError::DisputeInProgress
+    );
+    
+    // 原租期租金須已付清，新租金僅適用於續約期間
+    let total_payments = lease.total_payments_until(lease.end_date)?;
+    require!(
+        lease.paid_months >= total_payments,
+        ZuviError::RentOutstanding
+    );
+    
+    // 原末期以原租金計算至下一繳費日（或新結束日），扣除已收取的部分
+    let last_period = total_payments - 1;
+    let top_up = lease.period_rent_until(last_period, lease.renewal_end_date)?
+        .checked_sub(lease.period_rent(last_period)?)
+        .ok_or(ZuviError::AmountMismatch)?;
+    let platform_fee = top_up
+        .checked_mul(lease.fee_rate as u64).unwrap()
+        .checked_div(10000).unwrap();
+    
+    if top_up > 0 {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.tenant_token.to_account_info(),
+                    to: ctx.accounts.landlord_token.to_account_info(),
+                    authority: ctx.accounts.tenant.to_account_info(),
+                },
+            ),
+            top_up - platform_fee,
+        )?;
+        
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.tenant_token.to_account_info(),
+                    to: ctx.accounts.fee_receiver_token.to_account_info(),
+                    authority: ctx.accounts.tenant.to_account_info(),
+                },
+            ),
+            platform_fee,
+        )?;
+    }
+    
+    let previous_end_date = lease.end_date;
+    lease.end_date = lease.renewal_end_date;
+    lease.rent = lease.renewal_rent;
+    // 續約租金為新的固定租金，不再套用原調漲排程
+    lease.escalation_bps = 0;
+    lease.escalation_cap_bps = 0;
+    lease.renewal_end_date = 0;
+    lease.renewal_rent = 0;
+    
+    emit!(RenewalAccepted {
+        lease: lease.key(),
+        escrow: escrow.key(),
+        tenant: lease.tenant,
+        previous_end_date,
+        new_end_date: lease.end_date,
+        new_rent: lease.rent,
+        deposit: escrow.amount,
+        prorated_top_up: top_up,
+    });
+    
+    msg!("續約完成");
+    msg!("租期延長至: {}", lease.end_date);
+    msg!("新月租金: {} USDC", lease.rent);
+    if top_up > 0 {
+        msg!("補收原末期租金差額: {} USDC", top_up);
+    }
+    msg!("押金 {} USDC 持續託管", escrow.amount);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptRenewal<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Account<'info, Lease>,
+    
+    #[account(
+        seeds = [ESCROW_SEED, lease.key().as_ref()],
+        bump,
+        constraint = escrow.lease == lease.key()
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    pub tenant: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = tenant_token.owner == tenant.key(),
+        constraint = tenant_token.mint == config.usdc_mint
+    )]
+    pub tenant_token: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = landlord_token.owner == lease.landlord,
+        constraint = landlord_token.mint == config.usdc_mint
+    )]
+    pub landlord_token: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = fee_receiver_token.owner == config.fee_receiver,
+        constraint = fee_receiver_token.mint == config.usdc_mint
+    )]
+    pub fee_receiver_token: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
@@ -10,6 +10,11 @@ pub fn apply_lease(
     let listing = &ctx.accounts.listing;
     let applicant = &ctx.accounts.applicant;
     
+    require!(
+        !config.is_paused(PAUSE_APPLY_LEASE),
+        ZuviError::ProgramPaused
+    );
+    
     require!(
         config.initialized,
         ZuviError::NotInitialized
@@ -6,11 +6,17 @@ pub fn approve_application(
     applicant: Pubkey,
     _created_at: i64,
 ) -> Result<()> {
+    let config = &ctx.accounts.config;
     let listing = &mut ctx.accounts.listing;
     let application = &mut ctx.accounts.application;
     
     require!(
-        listing.owner == ctx.accounts.owner.key(),
+        !config.is_paused(PAUSE_APPROVE_APPLICATION),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        listing.is_authorized(&ctx.accounts.owner.key(), MANAGER_PERMISSION_APPROVE_APPLICATION),
         ZuviError::Unauthorized
     );
     
@@ -46,7 +52,7 @@ pub fn approve_application(
         application: application.key(),
         listing: listing.key(),
         applicant,
-        owner: ctx.accounts.owner.key(),
+        owner: listing.owner,
     });
     
     msg!("申請已核准");
@@ -59,6 +65,9 @@ pub fn approve_application(
 #[derive(Accounts)]
 #[instruction(applicant: Pubkey, _created_at: i64)]
 pub struct ApproveApplication<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
     #[account(
         mut,
         seeds = [LISTING_SEED, listing.property_attest.as_ref()],
@@ -74,5 +83,6 @@ pub struct ApproveApplication<'info> {
     )]
     pub application: Account<'info, Application>,
     
+    /// 房東或具核准申請權限的管理人
     pub owner: Signer<'info>,
 }
\ No newline at end of file
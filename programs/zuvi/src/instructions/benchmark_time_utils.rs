@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::log::sol_log_compute_units;
+use crate::{constants::*, time_utils::TimeUtils};
+
+/// 記錄 TimeUtils 日期計算消耗的運算單位（僅於啟用 cu-bench 功能時編譯）
+pub fn benchmark_time_utils(_ctx: Context<BenchmarkTimeUtils>, lease_start: i64, current_time: i64) -> Result<()> {
+    msg!("timestamp_to_date");
+    sol_log_compute_units();
+    let (year, month, day) = TimeUtils::timestamp_to_date(current_time)?;
+    sol_log_compute_units();
+
+    msg!("date_to_timestamp");
+    sol_log_compute_units();
+    let timestamp = TimeUtils::date_to_timestamp(year, month, day)?;
+    sol_log_compute_units();
+
+    msg!("calculate_months_due");
+    sol_log_compute_units();
+    let months_due = TimeUtils::calculate_months_due(
+        current_time,
+        lease_start,
+        MIN_PAYMENT_DAY,
+        PAYMENT_FREQUENCY_MONTHLY,
+        DEFAULT_UTC_OFFSET_MINUTES,
+    )?;
+    sol_log_compute_units();
+
+    msg!("{}-{}-{} ({}), 已到期 {} 期", year, month, day, timestamp, months_due);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BenchmarkTimeUtils<'info> {
+    pub signer: Signer<'info>,
+}
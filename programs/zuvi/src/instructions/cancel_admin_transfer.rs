@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 取消待接受的管理員轉移
+pub fn cancel_admin_transfer(ctx: Context<CancelAdminTransfer>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    // 確認是管理員
+    require!(
+        config.admin == ctx.accounts.admin.key(),
+        ZuviError::NotAdmin
+    );
+    
+    // 確認有待接受的轉移
+    let cancelled_admin = config.pending_admin.ok_or(ZuviError::NoPendingAdminTransfer)?;
+    
+    config.pending_admin = None;
+    
+    emit!(AdminTransferCancelled {
+        config: config.key(),
+        admin: config.admin,
+        cancelled_admin,
+    });
+    
+    msg!("管理員轉移已取消");
+    msg!("已取消的新管理員: {}", cancelled_admin);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelAdminTransfer<'info> {
+    /// 系統配置帳戶
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// 現任管理員
+    pub admin: Signer<'info>,
+}
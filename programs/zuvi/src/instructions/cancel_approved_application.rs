@@ -2,10 +2,16 @@ use anchor_lang::prelude::*;
 use crate::{constants::*, errors::*, events::*, state::*};
 
 pub fn cancel_approved_application(ctx: Context<CancelApprovedApplication>, _applicant: Pubkey, _created_at: i64) -> Result<()> {
+    let config = &ctx.accounts.config;
     let listing = &mut ctx.accounts.listing;
     let application = &mut ctx.accounts.application;
     let signer = &ctx.accounts.signer;
     
+    require!(
+        !config.is_paused(PAUSE_CANCEL_APPROVED_APPLICATION),
+        ZuviError::ProgramPaused
+    );
+    
     require!(
         application.applicant == signer.key() || listing.owner == signer.key(),
         ZuviError::Unauthorized
@@ -40,6 +46,9 @@ pub fn cancel_approved_application(ctx: Context<CancelApprovedApplication>, _app
 #[derive(Accounts)]
 #[instruction(_applicant: Pubkey, _created_at: i64)]
 pub struct CancelApprovedApplication<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
     #[account(
         mut,
         seeds = [LISTING_SEED, listing.property_attest.as_ref()],
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 承租人撤回尚未結算的提前終止申請
+pub fn cancel_early_termination(ctx: Context<CancelEarlyTermination>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    let tenant = &ctx.accounts.tenant;
+    
+    require!(
+        !config.is_paused(PAUSE_CANCEL_EARLY_TERMINATION),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        tenant.key() == lease.tenant,
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    require!(
+        lease.early_termination_requested,
+        ZuviError::NoTerminationRequest
+    );
+    
+    let termination_date = lease.termination_date;
+    lease.early_termination_requested = false;
+    lease.termination_date = 0;
+    lease.termination_penalty = 0;
+    
+    emit!(EarlyTerminationCancelled {
+        lease: lease.key(),
+        cancelled_by: tenant.key(),
+        termination_date,
+    });
+    
+    msg!("提前終止申請已取消");
+    msg!("承租人: {}", tenant.key());
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelEarlyTermination<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Account<'info, Lease>,
+    
+    pub tenant: Signer<'info>,
+}
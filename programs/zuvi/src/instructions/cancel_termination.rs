@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 提議人撤回或另一方拒絕尚未確認的合意終止提議
+pub fn cancel_termination(ctx: Context<CancelTermination>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    let signer = &ctx.accounts.signer;
+    
+    require!(
+        !config.is_paused(PAUSE_CANCEL_TERMINATION),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        signer.key() == lease.landlord || signer.key() == lease.tenant,
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    let proposer = lease.termination_proposer.ok_or(ZuviError::NoTerminationRequest)?;
+    let termination_date = lease.termination_date;
+    lease.termination_proposer = None;
+    lease.termination_date = 0;
+    lease.termination_refund = 0;
+    
+    emit!(TerminationCancelled {
+        lease: lease.key(),
+        proposer,
+        cancelled_by: signer.key(),
+        termination_date,
+    });
+    
+    msg!("合意終止提議已取消");
+    msg!("取消者: {}", signer.key());
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelTermination<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Account<'info, Lease>,
+    
+    pub signer: Signer<'info>,
+}
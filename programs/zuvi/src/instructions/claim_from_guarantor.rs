@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, Token, Transfer};
+use anchor_spl::token_interface::TokenAccount;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 承租人逾期欠租時，房東向保證人請求代償最早未繳的一期
+pub fn claim_from_guarantor(ctx: Context<ClaimFromGuarantor>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    let clock = Clock::get()?;
+    
+    require!(
+        !config.is_paused(PAUSE_CLAIM_FROM_GUARANTOR),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.landlord == ctx.accounts.landlord.key(),
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    require!(
+        lease.landlord_signed && lease.tenant_signed,
+        ZuviError::NotSigned
+    );
+    
+    let guarantor = lease.guarantor.ok_or(ZuviError::NoGuarantor)?;
+    require!(
+        lease.guarantor_signed,
+        ZuviError::GuarantorNotSigned
+    );
+    require!(
+        ctx.accounts.guarantor_token.owner == guarantor
+            && ctx.accounts.guarantor_token.delegate == COption::Some(lease.key()),
+        ZuviError::InvalidParameter
+    );
+    
+    // 共同承租人已分攤部分款項時不可再全額代償
+    require!(
+        lease.contribution_mask == 0,
+        ZuviError::PartialContributionPending
+    );
+    
+    // 最早未繳的一期須已到期且超過寬限期
+    let total_payments = lease.total_payments_until(lease.end_date)?;
+    require!(
+        lease.paid_months < total_payments,
+        ZuviError::NotInArrears
+    );
+    let due_date = lease.payment_due(lease.paid_months)?;
+    require!(
+        clock.unix_timestamp >= due_date + lease.grace_days as i64 * SECONDS_PER_DAY,
+        ZuviError::NotInArrears
+    );
+    
+    let charge = lease.rent_charge(lease.paid_months, clock.unix_timestamp)?;
+    let landlord_amount = charge.landlord_amount();
+    
+    let start_date_bytes = lease.start_date.to_le_bytes();
+    let lease_seeds = &[
+        LEASE_SEED,
+        lease.listing.as_ref(),
+        lease.original_tenant.as_ref(),
+        start_date_bytes.as_ref(),
+        &[ctx.bumps.lease],
+    ];
+    let signer_seeds = &[&lease_seeds[..]];
+    
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.guarantor_token.to_account_info(),
+                to: ctx.accounts.landlord_token.to_account_info(),
+                authority: lease.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        landlord_amount,
+    )?;
+    
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.guarantor_token.to_account_info(),
+                to: ctx.accounts.fee_receiver_token.to_account_info(),
+                authority: lease.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        charge.platform_fee,
+    )?;
+    
+    lease.paid_months += 1;
+    lease.last_payment = clock.unix_timestamp;
+    
+    emit!(RentPaid {
+        lease: lease.key(),
+        tenant: lease.tenant,
+        payer: guarantor,
+        landlord: lease.landlord,
+        month: charge.month,
+        rent_amount: charge.rent_amount,
+        discount: charge.discount,
+        amount: charge.rent_amount - charge.platform_fee,
+        platform_fee: charge.platform_fee,
+        late_fee: charge.late_fee,
+        payment_date: lease.last_payment,
+    });
+    
+    emit!(GuarantorClaimed {
+        lease: lease.key(),
+        landlord: lease.landlord,
+        guarantor,
+        month: charge.month,
+        amount: landlord_amount,
+        platform_fee: charge.platform_fee,
+    });
+    
+    msg!("已向保證人請求代償: 第 {} 期", charge.month);
+    msg!("房東收到: {} USDC", landlord_amount);
+    msg!("平台費: {} USDC", charge.platform_fee);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimFromGuarantor<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Box<Account<'info, Lease>>,
+    
+    pub landlord: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = guarantor_token.mint == config.usdc_mint
+    )]
+    pub guarantor_token: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = landlord_token.owner == lease.landlord,
+        constraint = landlord_token.mint == config.usdc_mint
+    )]
+    pub landlord_token: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = fee_receiver_token.owner == config.fee_receiver,
+        constraint = fee_receiver_token.mint == config.usdc_mint
+    )]
+    pub fee_receiver_token: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
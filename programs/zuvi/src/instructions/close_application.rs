@@ -2,9 +2,15 @@ use anchor_lang::prelude::*;
 use crate::{constants::*, errors::*, events::*, state::*};
 
 pub fn close_application(ctx: Context<CloseApplication>) -> Result<()> {
+    let config = &ctx.accounts.config;
     let application = &ctx.accounts.application;
     let applicant = &ctx.accounts.applicant;
     
+    require!(
+        !config.is_paused(PAUSE_CLOSE_APPLICATION),
+        ZuviError::ProgramPaused
+    );
+    
     require!(
         application.applicant == applicant.key(),
         ZuviError::Unauthorized
@@ -30,6 +36,9 @@ pub fn close_application(ctx: Context<CloseApplication>) -> Result<()> {
 #[derive(Accounts)]
 #[instruction(_applicant: Pubkey, _created_at: i64)]
 pub struct CloseApplication<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
     #[account(
         mut,
         close = applicant,
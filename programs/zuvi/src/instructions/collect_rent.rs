@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, Token, Transfer};
+use anchor_spl::token_interface::TokenAccount;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 自動扣款：租金到期後任何人皆可呼叫，以租約 PDA 的委派額度扣繳一期租金
+pub fn collect_rent(ctx: Context<CollectRent>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    let clock = Clock::get()?;
+    
+    require!(
+        !config.is_paused(PAUSE_COLLECT_RENT),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    require!(
+        lease.landlord_signed && lease.tenant_signed,
+        ZuviError::NotSigned
+    );
+    
+    // 承租人可隨時以 SPL Token revoke 撤銷委派
+    require!(
+        lease.auto_debit_cap > 0 && ctx.accounts.tenant_token.delegate == COption::Some(lease.key()),
+        ZuviError::AutoDebitNotEnabled
+    );
+    
+    // 僅扣繳已到期的一期
+    let total_payments = lease.total_payments_until(lease.end_date)?;
+    let payments_due = lease.payments_due(clock.unix_timestamp)?.min(total_payments);
+    require!(
+        lease.paid_months < payments_due,
+        ZuviError::PaymentNotDue
+    );
+    
+    // 共同承租人已分攤部分款項時不可再全額扣款
+    require!(
+        lease.contribution_mask == 0,
+        ZuviError::PartialContributionPending
+    );
+    
+    let charge = lease.rent_charge(lease.paid_months, clock.unix_timestamp)?;
+    let landlord_amount = charge.landlord_amount();
+    require!(
+        ctx.accounts.tenant_token.delegated_amount >= landlord_amount + charge.platform_fee,
+        ZuviError::AutoDebitAllowanceExceeded
+    );
+    
+    let start_date_bytes = lease.start_date.to_le_bytes();
+    let lease_seeds = &[
+        LEASE_SEED,
+        lease.listing.as_ref(),
+        lease.original_tenant.as_ref(),
+        start_date_bytes.as_ref(),
+        &[ctx.bumps.lease],
+    ];
+    let signer_seeds = &[&lease_seeds[..]];
+    
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.tenant_token.to_account_info(),
+                to: ctx.accounts.landlord_token.to_account_info(),
+                authority: lease.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        landlord_amount,
+    )?;
+    
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.tenant_token.to_account_info(),
+                to: ctx.accounts.fee_receiver_token.to_account_info(),
+                authority: lease.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        charge.platform_fee,
+    )?;
+    
+    lease.paid_months += 1;
+    lease.last_payment = clock.unix_timestamp;
+    
+    emit!(RentPaid {
+        lease: lease.key(),
+        tenant: lease.tenant,
+        payer: lease.tenant,
+        landlord: lease.landlord,
+        month: charge.month,
+        rent_amount: charge.rent_amount,
+        discount: charge.discount,
+        amount: charge.rent_amount - charge.platform_fee,
+        platform_fee: charge.platform_fee,
+        late_fee: charge.late_fee,
+        payment_date: lease.last_payment,
+    });
+    
+    msg!("自動扣款完成: 第 {} 期", charge.month);
+    msg!("房東收到: {} USDC", landlord_amount);
+    msg!("平台費: {} USDC", charge.platform_fee);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CollectRent<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Box<Account<'info, Lease>>,
+    
+    #[account(
+        mut,
+        constraint = tenant_token.owner == lease.tenant,
+        constraint = tenant_token.mint == config.usdc_mint
+    )]
+    pub tenant_token: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = landlord_token.owner == lease.landlord,
+        constraint = landlord_token.mint == config.usdc_mint
+    )]
+    pub landlord_token: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = fee_receiver_token.owner == config.fee_receiver,
+        constraint = fee_receiver_token.mint == config.usdc_mint
+    )]
+    pub fee_receiver_token: InterfaceAccount<'info, TokenAccount>,
+    
+    /// 觸發扣款的任意帳戶
+    pub cranker: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 租期屆滿後結束租約（任何人皆可呼叫）
+pub fn complete_lease(ctx: Context<CompleteLease>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let listing = &mut ctx.accounts.listing;
+    let lease = &mut ctx.accounts.lease;
+    let clock = Clock::get()?;
+    
+    require!(
+        !config.is_paused(PAUSE_COMPLETE_LEASE),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    require!(
+        lease.landlord_signed && lease.tenant_signed,
+        ZuviError::NotSigned
+    );
+    
+    require!(
+        clock.unix_timestamp >= lease.end_date,
+        ZuviError::LeaseNotEnded
+    );
+    
+    let total_payments = lease.total_payments_until(lease.end_date)?;
+    require!(
+        lease.paid_months >= total_payments,
+        ZuviError::RentOutstanding
+    );
+    
+    lease.status = LEASE_STATUS_COMPLETED;
+    listing.release_lease(&lease.key());
+    
+    emit!(LeaseCompleted {
+        lease: lease.key(),
+        listing: listing.key(),
+        landlord: lease.landlord,
+        tenant: lease.tenant,
+        paid_months: lease.paid_months,
+        completed_by: ctx.accounts.caller.key(),
+        completed_at: clock.unix_timestamp,
+    });
+    
+    msg!("租約已完成");
+    msg!("已付期數: {}", lease.paid_months);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CompleteLease<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [LISTING_SEED, listing.property_attest.as_ref()],
+        bump
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump,
+        constraint = lease.listing == listing.key()
+    )]
+    pub lease: Account<'info, Lease>,
+    
+    pub caller: Signer<'info>,
+}
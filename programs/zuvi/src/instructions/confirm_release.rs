@@ -1,14 +1,20 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, Transfer};
+use anchor_spl::token::Token;
 use anchor_spl::token_interface::TokenAccount;
-use crate::{constants::*, errors::*, events::ReleaseConfirmed, state::*};
+use crate::{constants::*, errors::*, escrow_utils::EscrowUtils, events::ReleaseConfirmed, state::*};
 
 pub fn confirm_release(ctx: Context<ConfirmRelease>) -> Result<()> {
+    let config = &ctx.accounts.config;
     let lease = &ctx.accounts.lease;
     let listing = &mut ctx.accounts.listing;
     let escrow = &mut ctx.accounts.escrow;
     let signer = &ctx.accounts.signer;
     
+    require!(
+        !config.is_paused(PAUSE_CONFIRM_RELEASE),
+        ZuviError::ProgramPaused
+    );
+    
     require!(
         signer.key() == lease.landlord || signer.key() == lease.tenant,
         ZuviError::Unauthorized
@@ -33,48 +39,31 @@ pub fn confirm_release(ctx: Context<ConfirmRelease>) -> Result<()> {
     }
     
     if escrow.landlord_signed && escrow.tenant_signed {
-        let lease_key = lease.key();
-        let escrow_seeds = &[
-            ESCROW_SEED,
-            lease_key.as_ref(),
-            &[ctx.bumps.escrow],
-        ];
-        let signer_seeds = &[&escrow_seeds[..]];
+        EscrowUtils::transfer_from_escrow(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.escrow_token.to_account_info(),
+            ctx.accounts.landlord_token.to_account_info(),
+            escrow.to_account_info(),
+            lease.key(),
+            ctx.bumps.escrow,
+            escrow.release_to_landlord,
+        )?;
         
-        if escrow.release_to_landlord > 0 {
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.escrow_token.to_account_info(),
-                        to: ctx.accounts.landlord_token.to_account_info(),
-                        authority: escrow.to_account_info(),
-                    },
-                    signer_seeds,
-                ),
-                escrow.release_to_landlord,
-            )?;
-        }
-        
-        if escrow.release_to_tenant > 0 {
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.escrow_token.to_account_info(),
-                        to: ctx.accounts.tenant_token.to_account_info(),
-                        authority: escrow.to_account_info(),
-                    },
-                    signer_seeds,
-                ),
-                escrow.release_to_tenant,
-            )?;
-        }
+        EscrowUtils::transfer_from_escrow(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.escrow_token.to_account_info(),
+            ctx.accounts.tenant_token.to_account_info(),
+            escrow.to_account_info(),
+            lease.key(),
+            ctx.bumps.escrow,
+            escrow.release_to_tenant,
+        )?;
         
         escrow.status = ESCROW_STATUS_RELEASED;
-        listing.status = LISTING_STATUS_AVAILABLE;
-        listing.current_tenant = None;
-        listing.has_active_lease = false;
+        // 租約已由其他流程結束時，房源可能已有新租約，不再重設
+        if lease.status == LEASE_STATUS_ACTIVE {
+            listing.release_lease(&lease.key());
+        }
         
         emit!(ReleaseConfirmed {
             escrow: escrow.key(),
@@ -109,7 +98,7 @@ pub struct ConfirmRelease<'info> {
     pub listing: Box<Account<'info, Listing>>,
     
     #[account(
-        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
         bump,
         constraint = lease.listing == listing.key()
     )]
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::TokenAccount;
+use crate::{constants::*, errors::*, escrow_utils::EscrowUtils, events::TerminationConfirmed, state::*};
+
+/// 另一方於終止日後確認合意終止，終止日前的租金須已付清
+pub fn confirm_termination(ctx: Context<ConfirmTermination>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let listing = &mut ctx.accounts.listing;
+    let lease = &mut ctx.accounts.lease;
+    let escrow = &mut ctx.accounts.escrow;
+    let signer = &ctx.accounts.signer;
+    let clock = Clock::get()?;
+    
+    require!(
+        !config.is_paused(PAUSE_CONFIRM_TERMINATION),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        signer.key() == lease.landlord || signer.key() == lease.tenant,
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    // 須由提議人以外的另一方確認
+    let proposer = lease.termination_proposer.ok_or(ZuviError::NoTerminationRequest)?;
+    require!(
+        proposer != signer.key(),
+        ZuviError::AlreadySigned
+    );
+    
+    require!(
+        clock.unix_timestamp >= lease.termination_date,
+        ZuviError::LeaseNotEnded
+    );
+    
+    let payments_due = lease.total_payments_until(lease.termination_date)?;
+    require!(
+        lease.paid_months >= payments_due,
+        ZuviError::RentOutstanding
+    );
+    
+    require!(
+        escrow.status == ESCROW_STATUS_HOLDING,
+        ZuviError::DepositAlreadyReleased
+    );
+    
+    require!(
+        !escrow.has_dispute,
+        ZuviError::DisputeInProgress
+    );
+    
+    require!(
+        lease.termination_refund <= escrow.amount,
+        ZuviError::AmountMismatch
+    );
+    
+    // 退還租金以押金抵付
+    EscrowUtils::transfer_from_escrow(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_token.to_account_info(),
+        ctx.accounts.tenant_token.to_account_info(),
+        escrow.to_account_info(),
+        lease.key(),
+        ctx.bumps.escrow,
+        lease.termination_refund,
+    )?;
+    
+    escrow.amount -= lease.termination_refund;
+    lease.termination_proposer = None;
+    lease.status = LEASE_STATUS_TERMINATED;
+    listing.release_lease(&lease.key());
+    
+    emit!(TerminationConfirmed {
+        lease: lease.key(),
+        listing: listing.key(),
+        escrow: escrow.key(),
+        landlord: lease.landlord,
+        tenant: lease.tenant,
+        termination_date: lease.termination_date,
+        refund_amount: lease.termination_refund,
+        remaining_deposit: escrow.amount,
+    });
+    
+    msg!("租約已合意終止");
+    msg!("終止日: {}", lease.termination_date);
+    msg!("承租人收到退還租金: {} USDC", lease.termination_refund);
+    msg!("剩餘押金: {} USDC", escrow.amount);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConfirmTermination<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        mut,
+        seeds = [LISTING_SEED, listing.property_attest.as_ref()],
+        bump
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump,
+        constraint = lease.listing == listing.key()
+    )]
+    pub lease: Box<Account<'info, Lease>>,
+    
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, lease.key().as_ref()],
+        bump
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    
+    pub signer: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = escrow_token.owner == escrow.key(),
+        constraint = escrow_token.mint == config.usdc_mint
+    )]
+    pub escrow_token: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = tenant_token.owner == lease.tenant,
+        constraint = tenant_token.mint == config.usdc_mint
+    )]
+    pub tenant_token: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{constants::*, errors::*, events::*, state::*};
+use crate::{constants::*, errors::*, events::*, state::*, time_utils::TimeUtils};
 
 pub fn create_lease(
     ctx: Context<CreateLease>,
@@ -10,12 +10,18 @@ pub fn create_lease(
     payment_day: u8,
     contract_uri: [u8; 64],
 ) -> Result<()> {
+    let config = &ctx.accounts.config;
     let listing = &ctx.accounts.listing;
     let application = &ctx.accounts.application;
     let clock = Clock::get()?;
     
     require!(
-        listing.owner == ctx.accounts.landlord.key(),
+        !config.is_paused(PAUSE_CREATE_LEASE),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        listing.is_authorized(&ctx.accounts.landlord.key(), MANAGER_PERMISSION_CREATE_LEASE),
         ZuviError::Unauthorized
     );
     
@@ -46,20 +52,33 @@ pub fn create_lease(
         end_date > start_date,
         ZuviError::InvalidDate
     );
+    TimeUtils::validate_timestamp(start_date)?;
+    TimeUtils::validate_timestamp(end_date)?;
     
     require!(
-        payment_day >= MIN_PAYMENT_DAY && payment_day <= MAX_PAYMENT_DAY,
+        (MIN_PAYMENT_DAY..=MAX_PAYMENT_DAY).contains(&payment_day),
         ZuviError::InvalidPaymentDay
     );
     
+    // 確認預設繳費排程下的到期日皆可計算
+    TimeUtils::calculate_total_payments(
+        start_date,
+        end_date,
+        payment_day,
+        PAYMENT_FREQUENCY_MONTHLY,
+        DEFAULT_UTC_OFFSET_MINUTES,
+    )?;
+    
     let lease = &mut ctx.accounts.lease;
     
     lease.listing = listing.key();
-    lease.landlord = ctx.accounts.landlord.key();
+    // 管理人代為建立時，租金仍歸房東
+    lease.landlord = listing.owner;
     lease.tenant = applicant;
     lease.tenant_attest = application.tenant_attest;
     lease.rent = listing.rent;
     lease.deposit = listing.deposit;
+    lease.fee_rate = 0;
     lease.start_date = start_date;
     lease.end_date = end_date;
     lease.payment_day = payment_day;
@@ -69,6 +88,33 @@ pub fn create_lease(
     lease.status = LEASE_STATUS_ACTIVE;
     lease.landlord_signed = true;
     lease.tenant_signed = false;
+    lease.early_termination_requested = false;
+    lease.termination_date = 0;
+    lease.termination_penalty = 0;
+    lease.termination_proposer = None;
+    lease.termination_refund = 0;
+    lease.renewal_end_date = 0;
+    lease.renewal_rent = 0;
+    lease.escalation_bps = 0;
+    lease.escalation_cap_bps = 0;
+    lease.grace_days = 0;
+    lease.late_fee_type = LATE_FEE_TYPE_NONE;
+    lease.late_fee_value = 0;
+    lease.prepay_discount_bps = 0;
+    lease.payment_frequency = PAYMENT_FREQUENCY_MONTHLY;
+    lease.utc_offset_minutes = DEFAULT_UTC_OFFSET_MINUTES;
+    lease.auto_debit_cap = 0;
+    lease.payer_restricted = false;
+    lease.approved_payers = Vec::new();
+    lease.co_tenants = Vec::new();
+    lease.contribution_mask = 0;
+    lease.period_landlord_funded = 0;
+    lease.period_fee_funded = 0;
+    lease.period_charge = None;
+    lease.guarantor = None;
+    lease.guarantor_signed = false;
+    lease.original_tenant = applicant;
+    lease.terms_revision = 0;
     
     emit!(LeaseCreated {
         lease: lease.key(),
@@ -92,6 +138,9 @@ pub fn create_lease(
 #[derive(Accounts)]
 #[instruction(applicant: Pubkey, _application_created_at: i64, start_date: i64)]
 pub struct CreateLease<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
     #[account(
         seeds = [LISTING_SEED, listing.property_attest.as_ref()],
         bump
@@ -113,6 +162,7 @@ pub struct CreateLease<'info> {
     )]
     pub lease: Account<'info, Lease>,
     
+    /// 房東或具建立租約權限的管理人
     #[account(mut)]
     pub landlord: Signer<'info>,
     
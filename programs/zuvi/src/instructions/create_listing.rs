@@ -12,6 +12,12 @@ pub fn create_listing(
 ) -> Result<()> {
     let config = &ctx.accounts.config;
     
+    // 確認功能未暫停
+    require!(
+        !config.is_paused(PAUSE_CREATE_LISTING),
+        ZuviError::ProgramPaused
+    );
+    
     // 確認系統已初始化
     require!(
         config.initialized,
@@ -48,6 +54,10 @@ pub fn create_listing(
     listing.has_active_lease = false;
     listing.has_approved_application = false;
     listing.created_at = clock.unix_timestamp;
+    listing.manager = None;
+    listing.manager_permissions = 0;
+    listing.owner_attest = listing.property_attest;
+    listing.current_lease = None;
     
     emit!(ListingCreated {
         listing: listing.key(),
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Approve, Revoke, Token};
+use anchor_spl::token_interface::TokenAccount;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 承租人於簽約後調整租約 PDA 代為扣繳租金的額度，供 collect_rent 於到期後自動扣款
+/// 額度為 0 時撤銷委派並停用自動扣款
+pub fn enable_auto_debit(ctx: Context<EnableAutoDebit>, auto_debit_cap: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    
+    require!(
+        !config.is_paused(PAUSE_ENABLE_AUTO_DEBIT),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.tenant == ctx.accounts.tenant.key(),
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    require!(
+        lease.landlord_signed && lease.tenant_signed,
+        ZuviError::NotSigned
+    );
+    
+    if auto_debit_cap > 0 {
+        token::approve(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Approve {
+                    to: ctx.accounts.tenant_token.to_account_info(),
+                    delegate: lease.to_account_info(),
+                    authority: ctx.accounts.tenant.to_account_info(),
+                },
+            ),
+            auto_debit_cap,
+        )?;
+    } else {
+        token::revoke(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Revoke {
+                    source: ctx.accounts.tenant_token.to_account_info(),
+                    authority: ctx.accounts.tenant.to_account_info(),
+                },
+            ),
+        )?;
+    }
+    lease.auto_debit_cap = auto_debit_cap;
+    
+    emit!(AutoDebitSet {
+        lease: lease.key(),
+        tenant: lease.tenant,
+        auto_debit_cap,
+    });
+    
+    if auto_debit_cap > 0 {
+        msg!("已啟用自動扣款，額度: {} USDC", auto_debit_cap);
+    } else {
+        msg!("已停用自動扣款");
+    }
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EnableAutoDebit<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Box<Account<'info, Lease>>,
+    
+    pub tenant: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = tenant_token.owner == tenant.key(),
+        constraint = tenant_token.mint == config.usdc_mint
+    )]
+    pub tenant_token: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
@@ -19,14 +19,23 @@ pub fn initialize(
     let config = &mut ctx.accounts.config;
     
     // 設定系統配置
+    config.admin = ctx.accounts.authority.key();
+    config.pending_admin = None;
+    config.guardian = ctx.accounts.authority.key();
+    config.paused = 0;
+    config.allow_exit_when_paused = true;
     config.api_signer = api_signer;
     config.arbitrator = arbitrator;
     config.fee_receiver = fee_receiver;
     config.usdc_mint = usdc_mint;
     config.fee_rate = fee_rate;
+    config.pending_fee_rate = None;
+    config.fee_rate_effective_at = 0;
+    config.arrears_termination_months = DEFAULT_ARREARS_TERMINATION_MONTHS;
     config.initialized = true;
 
     msg!("系統初始化成功");
+    msg!("管理員: {}", config.admin);
     msg!("API Signer: {}", api_signer);
     msg!("仲裁者: {}", arbitrator);
     msg!("費用接收者: {}", fee_receiver);
@@ -48,7 +57,7 @@ pub struct Initialize<'info> {
     )]
     pub config: Account<'info, Config>,
     
-    /// 初始化授權者（支付者）
+    /// 初始化授權者（支付者），同時成為管理員
     #[account(mut)]
     pub authority: Signer<'info>,
     
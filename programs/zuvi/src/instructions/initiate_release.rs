@@ -6,17 +6,23 @@ pub fn initiate_release(
     landlord_amount: u64,
     tenant_amount: u64,
 ) -> Result<()> {
+    let config = &ctx.accounts.config;
     let lease = &ctx.accounts.lease;
     let escrow = &mut ctx.accounts.escrow;
     let signer = &ctx.accounts.signer;
     
+    require!(
+        !config.is_paused(PAUSE_INITIATE_RELEASE),
+        ZuviError::ProgramPaused
+    );
+    
     require!(
         signer.key() == lease.landlord || signer.key() == lease.tenant,
         ZuviError::Unauthorized
     );
     
     require!(
-        escrow.status == ESCROW_STATUS_HOLDING,
+        escrow.status == ESCROW_STATUS_HOLDING || escrow.status == ESCROW_STATUS_FROZEN,
         ZuviError::DepositAlreadyReleased
     );
     
@@ -61,8 +67,11 @@ pub fn initiate_release(
 
 #[derive(Accounts)]
 pub struct InitiateRelease<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
     #[account(
-        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
         bump
     )]
     pub lease: Account<'info, Lease>,
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 承租人更換錢包：原錢包、新錢包與 API 簽名者共同簽署後，將租約與房源改綁至新錢包
+pub fn migrate_tenant(ctx: Context<MigrateTenant>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let listing = &mut ctx.accounts.listing;
+    let lease = &mut ctx.accounts.lease;
+    let new_tenant = ctx.accounts.new_tenant.key();
+    let old_tenant = lease.tenant;
+    
+    require!(
+        !config.is_paused(PAUSE_MIGRATE_TENANT),
+        ZuviError::ProgramPaused
+    );
+    
+    // 驗證 API 簽名者（已重新驗證承租人身分並核發新錢包的憑證）
+    require!(
+        ctx.accounts.api_signer.key() == config.api_signer,
+        ZuviError::ApiSignatureRequired
+    );
+    
+    require!(
+        ctx.accounts.old_tenant.key() == old_tenant,
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    // 新錢包不可與租約其他角色重複
+    require!(
+        new_tenant != old_tenant
+            && new_tenant != lease.landlord
+            && lease.guarantor != Some(new_tenant)
+            && !lease.co_tenants.iter().any(|co_tenant| co_tenant.tenant == new_tenant),
+        ZuviError::InvalidParameter
+    );
+    
+    lease.tenant = new_tenant;
+    lease.tenant_attest = ctx.accounts.tenant_attest.key();
+    lease.approved_payers.retain(|payer| *payer != new_tenant);
+    if lease.termination_proposer == Some(old_tenant) {
+        lease.termination_proposer = Some(new_tenant);
+    }
+    
+    // 舊錢包的委派扣款額度不再適用
+    lease.auto_debit_cap = 0;
+    
+    if listing.current_tenant == Some(old_tenant) {
+        listing.current_tenant = Some(new_tenant);
+    }
+    
+    emit!(TenantMigrated {
+        lease: lease.key(),
+        listing: listing.key(),
+        old_tenant,
+        new_tenant,
+        tenant_attest: lease.tenant_attest,
+    });
+    
+    msg!("承租人錢包已更換");
+    msg!("原錢包: {}", old_tenant);
+    msg!("新錢包: {}", new_tenant);
+    msg!("自動扣款已停用");
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateTenant<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(mut, seeds = [LISTING_SEED, listing.property_attest.as_ref()], bump)]
+    pub listing: Box<Account<'info, Listing>>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump,
+        constraint = lease.listing == listing.key()
+    )]
+    pub lease: Box<Account<'info, Lease>>,
+    
+    /// 原承租人
+    pub old_tenant: Signer<'info>,
+    
+    pub new_tenant: Signer<'info>,
+    
+    /// API 簽名者
+    pub api_signer: Signer<'info>,
+    
+    /// 新錢包的承租人憑證帳戶
+    /// CHECK: 由 API 驗證
+    pub tenant_attest: AccountInfo<'info>,
+}
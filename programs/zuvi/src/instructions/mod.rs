@@ -1,33 +1,101 @@
 pub mod initialize;
+pub mod update_config;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod cancel_admin_transfer;
+pub mod set_pause;
 pub mod create_listing;
 pub mod update_listing;
 pub mod toggle_listing;
+pub mod set_listing_manager;
+pub mod transfer_listing_ownership;
 pub mod apply_lease;
 pub mod close_application;
 pub mod cancel_approved_application;
 pub mod approve_application;
 pub mod reject_application;
 pub mod create_lease;
+pub mod set_rent_escalation;
+pub mod set_late_fee_policy;
+pub mod set_payment_schedule;
+pub mod set_co_tenants;
+pub mod set_guarantor;
 pub mod sign_lease;
+pub mod sign_lease_co_tenant;
+pub mod sign_guarantee;
 pub mod pay_rent;
+pub mod pay_rent_share;
+pub mod enable_auto_debit;
+pub mod collect_rent;
+pub mod set_rent_payers;
+pub mod prepay_rent;
+pub mod set_prepay_discount;
+pub mod complete_lease;
+pub mod request_early_termination;
+pub mod accept_early_termination;
+pub mod cancel_early_termination;
+pub mod terminate_for_arrears;
+pub mod claim_from_guarantor;
+pub mod migrate_tenant;
+pub mod propose_termination;
+pub mod confirm_termination;
+pub mod cancel_termination;
+pub mod offer_renewal;
+pub mod accept_renewal;
 pub mod initiate_release;
 pub mod confirm_release;
 pub mod raise_dispute;
 pub mod resolve_dispute;
+#[cfg(feature = "cu-bench")]
+pub mod benchmark_time_utils;
 
 pub use initialize::*;
+pub use update_config::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use cancel_admin_transfer::*;
+pub use set_pause::*;
 pub use create_listing::*;
 pub use update_listing::*;
 pub use toggle_listing::*;
+pub use set_listing_manager::*;
+pub use transfer_listing_ownership::*;
 pub use apply_lease::*;
 pub use close_application::*;
 pub use cancel_approved_application::*;
 pub use approve_application::*;
 pub use reject_application::*;
 pub use create_lease::*;
+pub use set_rent_escalation::*;
+pub use set_late_fee_policy::*;
+pub use set_payment_schedule::*;
+pub use set_co_tenants::*;
+pub use set_guarantor::*;
 pub use sign_lease::*;
+pub use sign_lease_co_tenant::*;
+pub use sign_guarantee::*;
 pub use pay_rent::*;
+pub use pay_rent_share::*;
+pub use enable_auto_debit::*;
+pub use collect_rent::*;
+pub use set_rent_payers::*;
+pub use prepay_rent::*;
+pub use set_prepay_discount::*;
+pub use complete_lease::*;
+pub use request_early_termination::*;
+pub use accept_early_termination::*;
+pub use cancel_early_termination::*;
+pub use terminate_for_arrears::*;
+pub use claim_from_guarantor::*;
+pub use migrate_tenant::*;
+pub use propose_termination::*;
+pub use confirm_termination::*;
+pub use cancel_termination::*;
+pub use offer_renewal::*;
+pub use accept_renewal::*;
 pub use initiate_release::*;
 pub use confirm_release::*;
 pub use raise_dispute::*;
-pub use resolve_dispute::*;
\ No newline at end of file
+pub use resolve_dispute::*;
+#[cfg(feature = "cu-bench")]
+pub use benchmark_time_utils::*;
\ No newline at end of file
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*, time_utils::TimeUtils};
+
+/// 房東提出續約（新結束日期與新租金）
+pub fn offer_renewal(
+    ctx: Context<OfferRenewal>,
+    new_end_date: i64,
+    new_rent: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    
+    require!(
+        !config.is_paused(PAUSE_OFFER_RENEWAL),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.landlord == ctx.accounts.landlord.key(),
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    require!(
+        lease.landlord_signed && lease.tenant_signed,
+        ZuviError::NotSigned
+    );
+    
+    require!(
+        !lease.early_termination_requested && lease.termination_proposer.is_none(),
+        ZuviError::TerminationAlreadyRequested
+    );
+    
+    require!(
+        new_end_date > lease.end_date,
+        ZuviError::InvalidDate
+    );
+    TimeUtils::validate_timestamp(new_end_date)?;
+    
+    require!(new_rent > 0, ZuviError::InvalidParameter);
+    
+    // 沿用原押金，不得超過新租金的押金上限
+    let max_deposit = new_rent.checked_mul(MAX_DEPOSIT_MONTHS as u64).unwrap();
+    require!(
+        lease.deposit <= max_deposit,
+        ZuviError::InvalidDepositAmount
+    );
+    
+    lease.renewal_end_date = new_end_date;
+    lease.renewal_rent = new_rent;
+    
+    emit!(RenewalOffered {
+        lease: lease.key(),
+        landlord: lease.landlord,
+        new_end_date,
+        new_rent,
+    });
+    
+    msg!("已提出續約");
+    msg!("新結束日: {}", new_end_date);
+    msg!("新月租金: {} USDC", new_rent);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct OfferRenewal<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Account<'info, Lease>,
+    
+    pub landlord: Signer<'info>,
+}
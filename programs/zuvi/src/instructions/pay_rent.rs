@@ -1,16 +1,32 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, Transfer};
 use anchor_spl::token_interface::TokenAccount;
-use crate::{constants::*, errors::*, events::*, state::*, time_utils::TimeUtils};
+use crate::{constants::*, errors::*, events::*, state::*};
 
-pub fn pay_rent(ctx: Context<PayRent>) -> Result<()> {
-    let config = &ctx.accounts.config;
+pub fn pay_rent(ctx: Context<PayRent>, months: u32) -> Result<()> {
+    require!(
+        !ctx.accounts.config.is_paused(PAUSE_PAY_RENT),
+        ZuviError::ProgramPaused
+    );
+    
+    settle_rent(ctx, months, false, None)
+}
+
+/// 支付租金共用流程，allow_prepay 時可支付尚未到期的期數
+/// for_tenant 指定共同承租人租約中代為繳納分攤租金的承租人
+pub(crate) fn settle_rent(
+    ctx: Context<PayRent>,
+    months: u32,
+    allow_prepay: bool,
+    for_tenant: Option<Pubkey>,
+) -> Result<()> {
     let lease = &mut ctx.accounts.lease;
     let clock = Clock::get()?;
     
+    // 承租人本人或其允許的代繳人皆可支付
     require!(
-        lease.tenant == ctx.accounts.tenant.key(),
-        ZuviError::Unauthorized
+        lease.can_pay_rent(&ctx.accounts.payer.key()),
+        ZuviError::PayerNotApproved
     );
     
     require!(
@@ -24,68 +40,185 @@ pub fn pay_rent(ctx: Context<PayRent>) -> Result<()> {
     );
     
     require!(
-        clock.unix_timestamp < lease.end_date,
+        months > 0 && months <= MAX_RENT_MONTHS_PER_PAYMENT,
+        ZuviError::InvalidParameter
+    );
+    
+    // 計算已到期未繳期數（不超過整個租期應付期數）
+    let total_payments = lease.total_payments_until(lease.end_date)?;
+    let payments_due = lease.payments_due(clock.unix_timestamp)?.min(total_payments);
+    let outstanding_months = payments_due.saturating_sub(lease.paid_months);
+    
+    // 租期結束後僅能補繳欠租
+    require!(
+        clock.unix_timestamp < lease.end_date || outstanding_months > 0,
         ZuviError::LeaseEnded
     );
     
+    if allow_prepay {
+        require!(
+            lease.paid_months + months <= total_payments,
+            ZuviError::PrepaymentExceedsLease
+        );
+    } else {
+        require!(
+            months <= outstanding_months,
+            ZuviError::PaymentNotDue
+        );
+    }
+    
+    // 共同承租人租約每次僅支付一期中一位承租人分攤的部分
+    if !lease.co_tenants.is_empty() {
+        require!(
+            !allow_prepay && months == 1,
+            ZuviError::InvalidParameter
+        );
+        let tenant = for_tenant.unwrap_or(ctx.accounts.payer.key());
+        return contribute_rent_share(ctx, tenant);
+    }
+    
     require!(
-        TimeUtils::is_rent_due(
-            clock.unix_timestamp,
-            lease.start_date,
-            lease.payment_day,
-            lease.paid_months
-        ),
-        ZuviError::PaymentNotDue
+        for_tenant.is_none(),
+        ZuviError::InvalidParameter
     );
     
-    let platform_fee = lease.rent
-        .checked_mul(config.fee_rate as u64).unwrap()
-        .checked_div(10000).unwrap();
-    let landlord_rent = lease.rent - platform_fee;
+    // 逐期計算租金明細，合併為單筆轉帳
+    let charges: Vec<RentCharge> = (lease.paid_months..lease.paid_months + months)
+        .map(|month_index| lease.rent_charge(month_index, clock.unix_timestamp))
+        .collect::<Result<_>>()?;
+    let landlord_total: u64 = charges.iter().map(|charge| charge.landlord_amount()).sum();
+    let platform_fee_total: u64 = charges.iter().map(|charge| charge.platform_fee).sum();
+    
+    transfer_rent(ctx.accounts, landlord_total, platform_fee_total)?;
+    
+    let lease = &mut ctx.accounts.lease;
+    lease.paid_months += months;
+    lease.last_payment = clock.unix_timestamp;
+    
+    for charge in charges.iter() {
+        emit!(RentPaid {
+            lease: lease.key(),
+            tenant: lease.tenant,
+            payer: ctx.accounts.payer.key(),
+            landlord: lease.landlord,
+            month: charge.month,
+            rent_amount: charge.rent_amount,
+            discount: charge.discount,
+            amount: charge.rent_amount - charge.platform_fee,
+            platform_fee: charge.platform_fee,
+            late_fee: charge.late_fee,
+            payment_date: lease.last_payment,
+        });
+    }
+    
+    msg!("租金已支付");
+    msg!("支付期數: {} (累計 {} 期)", months, lease.paid_months);
+    msg!("房東收到: {} USDC", landlord_total);
+    msg!("平台費: {} USDC", platform_fee_total);
+    
+    Ok(())
+}
+
+/// 共同承租人分攤支付當期租金，所有承租人皆繳納後才計入已付期數
+fn contribute_rent_share(ctx: Context<PayRent>, tenant: Pubkey) -> Result<()> {
+    let payer = ctx.accounts.payer.key();
+    let lease = &ctx.accounts.lease;
+    let clock = Clock::get()?;
+    
+    let (share_bit, share_bps) = lease.rent_share(&tenant).ok_or(ZuviError::NotCoTenant)?;
+    let share_flag = 1u8 << share_bit;
+    require!(
+        lease.contribution_mask & share_flag == 0,
+        ZuviError::AlreadyContributed
+    );
     
+    // 本期租金明細於首筆分攤款時鎖定，之後的滯納金不轉嫁給最後繳納者
+    let charge = match lease.period_charge.clone() {
+        Some(charge) => charge,
+        None => lease.rent_charge(lease.paid_months, clock.unix_timestamp)?,
+    };
+    let contribution_mask = lease.contribution_mask | share_flag;
+    let fully_funded = contribution_mask == lease.full_contribution_mask();
+    
+    let (landlord_amount, platform_fee) = lease.share_amounts(&charge, share_bps, fully_funded)?;
+    
+    transfer_rent(ctx.accounts, landlord_amount, platform_fee)?;
+    
+    let lease = &mut ctx.accounts.lease;
+    if fully_funded {
+        lease.paid_months += 1;
+        lease.last_payment = clock.unix_timestamp;
+        lease.contribution_mask = 0;
+        lease.period_landlord_funded = 0;
+        lease.period_fee_funded = 0;
+        lease.period_charge = None;
+        
+        emit!(RentPaid {
+            lease: lease.key(),
+            tenant: lease.tenant,
+            payer,
+            landlord: lease.landlord,
+            month: charge.month,
+            rent_amount: charge.rent_amount,
+            discount: charge.discount,
+            amount: charge.rent_amount - charge.platform_fee,
+            platform_fee: charge.platform_fee,
+            late_fee: charge.late_fee,
+            payment_date: lease.last_payment,
+        });
+    } else {
+        lease.contribution_mask = contribution_mask;
+        lease.period_landlord_funded += landlord_amount;
+        lease.period_fee_funded += platform_fee;
+        lease.period_charge = Some(charge.clone());
+    }
+    
+    emit!(RentContributed {
+        lease: lease.key(),
+        contributor: payer,
+        tenant,
+        month: charge.month,
+        landlord_amount,
+        platform_fee,
+        fully_funded,
+    });
+    
+    msg!("分攤租金已支付: 第 {} 期", charge.month);
+    msg!("分攤承租人: {}", tenant);
+    msg!("房東收到: {} USDC", landlord_amount);
+    msg!("平台費: {} USDC", platform_fee);
+    if fully_funded {
+        msg!("本期租金已全數繳清");
+    }
+    
+    Ok(())
+}
+
+/// 自付款人帳戶轉出房東款項與平台費
+fn transfer_rent(accounts: &PayRent, landlord_amount: u64, platform_fee: u64) -> Result<()> {
     token::transfer(
         CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
+            accounts.token_program.to_account_info(),
             Transfer {
-                from: ctx.accounts.tenant_token.to_account_info(),
-                to: ctx.accounts.landlord_token.to_account_info(),
-                authority: ctx.accounts.tenant.to_account_info(),
+                from: accounts.payer_token.to_account_info(),
+                to: accounts.landlord_token.to_account_info(),
+                authority: accounts.payer.to_account_info(),
             },
         ),
-        landlord_rent,
+        landlord_amount,
     )?;
     
     token::transfer(
         CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
+            accounts.token_program.to_account_info(),
             Transfer {
-                from: ctx.accounts.tenant_token.to_account_info(),
-                to: ctx.accounts.fee_receiver_token.to_account_info(),
-                authority: ctx.accounts.tenant.to_account_info(),
+                from: accounts.payer_token.to_account_info(),
+                to: accounts.fee_receiver_token.to_account_info(),
+                authority: accounts.payer.to_account_info(),
             },
         ),
         platform_fee,
-    )?;
-    
-    lease.paid_months += 1;
-    lease.last_payment = clock.unix_timestamp;
-    
-    emit!(RentPaid {
-        lease: lease.key(),
-        tenant: lease.tenant,
-        landlord: lease.landlord,
-        month: lease.paid_months,
-        amount: landlord_rent,
-        platform_fee,
-        payment_date: lease.last_payment,
-    });
-    
-    msg!("租金已支付");
-    msg!("第 {} 期租金", lease.paid_months);
-    msg!("房東收到: {} USDC", landlord_rent);
-    msg!("平台費: {} USDC", platform_fee);
-    
-    Ok(())
+    )
 }
 
 #[derive(Accounts)]
@@ -95,20 +228,21 @@ pub struct PayRent<'info> {
     
     #[account(
         mut,
-        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
         bump
     )]
     pub lease: Box<Account<'info, Lease>>,
     
+    /// 承租人或代繳人
     #[account(mut)]
-    pub tenant: Signer<'info>,
+    pub payer: Signer<'info>,
     
     #[account(
         mut,
-        constraint = tenant_token.owner == tenant.key(),
-        constraint = tenant_token.mint == config.usdc_mint
+        constraint = payer_token.owner == payer.key(),
+        constraint = payer_token.mint == config.usdc_mint
     )]
-    pub tenant_token: InterfaceAccount<'info, TokenAccount>,
+    pub payer_token: InterfaceAccount<'info, TokenAccount>,
     
     #[account(
         mut,
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, instructions::pay_rent::{settle_rent, PayRent}};
+
+/// 代共同承租人租約中的指定承租人支付當期分攤租金（代繳人、其他承租人皆可）
+pub fn pay_rent_share(ctx: Context<PayRent>, for_tenant: Pubkey) -> Result<()> {
+    require!(
+        !ctx.accounts.config.is_paused(PAUSE_PAY_RENT_SHARE),
+        ZuviError::ProgramPaused
+    );
+    
+    settle_rent(ctx, 1, false, Some(for_tenant))
+}
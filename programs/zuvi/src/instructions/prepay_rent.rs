@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, instructions::pay_rent::{settle_rent, PayRent}};
+
+/// 預付租金：先補繳已到期期數，其餘未到期期數套用預付折扣
+pub fn prepay_rent(ctx: Context<PayRent>, months: u32) -> Result<()> {
+    require!(
+        !ctx.accounts.config.is_paused(PAUSE_PREPAY_RENT),
+        ZuviError::ProgramPaused
+    );
+    
+    settle_rent(ctx, months, true, None)
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 提議轉移管理員權限（需由新管理員接受）
+pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    // 確認是管理員
+    require!(
+        config.admin == ctx.accounts.admin.key(),
+        ZuviError::NotAdmin
+    );
+    
+    // 不能轉移給自己
+    require!(
+        new_admin != config.admin,
+        ZuviError::InvalidParameter
+    );
+    
+    config.pending_admin = Some(new_admin);
+    
+    emit!(AdminTransferProposed {
+        config: config.key(),
+        admin: config.admin,
+        pending_admin: new_admin,
+    });
+    
+    msg!("管理員轉移已提議");
+    msg!("待接受的新管理員: {}", new_admin);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    /// 系統配置帳戶
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// 現任管理員
+    pub admin: Signer<'info>,
+}
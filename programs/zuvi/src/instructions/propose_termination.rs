@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+pub fn propose_termination(
+    ctx: Context<ProposeTermination>,
+    termination_date: i64,
+    refund_amount: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    let signer = &ctx.accounts.signer;
+    let clock = Clock::get()?;
+    
+    require!(
+        !config.is_paused(PAUSE_PROPOSE_TERMINATION),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        signer.key() == lease.landlord || signer.key() == lease.tenant,
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    require!(
+        lease.landlord_signed && lease.tenant_signed,
+        ZuviError::NotSigned
+    );
+    
+    // 提議人可修改自己的提議，但不能覆蓋對方的提議
+    require!(
+        !lease.early_termination_requested,
+        ZuviError::TerminationAlreadyRequested
+    );
+    if let Some(proposer) = lease.termination_proposer {
+        require!(
+            proposer == signer.key(),
+            ZuviError::TerminationAlreadyRequested
+        );
+    }
+    
+    require!(
+        termination_date >= clock.unix_timestamp && termination_date < lease.end_date,
+        ZuviError::InvalidDate
+    );
+    
+    lease.termination_proposer = Some(signer.key());
+    lease.termination_date = termination_date;
+    lease.termination_refund = refund_amount;
+    
+    emit!(TerminationProposed {
+        lease: lease.key(),
+        proposer: signer.key(),
+        termination_date,
+        refund_amount,
+    });
+    
+    msg!("合意終止已提議");
+    msg!("終止日: {}", termination_date);
+    msg!("退還租金: {} USDC", refund_amount);
+    msg!("等待另一方確認");
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeTermination<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Account<'info, Lease>,
+    
+    pub signer: Signer<'info>,
+}
@@ -2,12 +2,18 @@ use anchor_lang::prelude::*;
 use crate::{constants::*, errors::*, events::*, state::*};
 
 pub fn raise_dispute(ctx: Context<RaiseDispute>, reason: u8) -> Result<()> {
+    let config = &ctx.accounts.config;
     let lease = &ctx.accounts.lease;
     let escrow = &mut ctx.accounts.escrow;
     let dispute = &mut ctx.accounts.dispute;
     let initiator = &ctx.accounts.initiator;
     let clock = Clock::get()?;
     
+    require!(
+        !config.is_paused(PAUSE_RAISE_DISPUTE),
+        ZuviError::ProgramPaused
+    );
+    
     require!(
         initiator.key() == lease.landlord || initiator.key() == lease.tenant,
         ZuviError::Unauthorized
@@ -49,8 +55,11 @@ pub fn raise_dispute(ctx: Context<RaiseDispute>, reason: u8) -> Result<()> {
 
 #[derive(Accounts)]
 pub struct RaiseDispute<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
     #[account(
-        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
         bump
     )]
     pub lease: Account<'info, Lease>,
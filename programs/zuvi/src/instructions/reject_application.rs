@@ -6,9 +6,15 @@ pub fn reject_application(
     applicant: Pubkey,
     _created_at: i64,
 ) -> Result<()> {
+    let config = &ctx.accounts.config;
     let listing = &ctx.accounts.listing;
     let application = &mut ctx.accounts.application;
     
+    require!(
+        !config.is_paused(PAUSE_REJECT_APPLICATION),
+        ZuviError::ProgramPaused
+    );
+    
     require!(
         listing.owner == ctx.accounts.owner.key(),
         ZuviError::Unauthorized
@@ -43,6 +49,9 @@ pub fn reject_application(
 #[derive(Accounts)]
 #[instruction(applicant: Pubkey, _created_at: i64)]
 pub struct RejectApplication<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
     #[account(
         seeds = [LISTING_SEED, listing.property_attest.as_ref()],
         bump
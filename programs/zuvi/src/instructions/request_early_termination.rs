@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 承租人申請提前終止租約，違約金由房東接受時決定
+pub fn request_early_termination(ctx: Context<RequestEarlyTermination>, termination_date: i64) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    let clock = Clock::get()?;
+    
+    require!(
+        !config.is_paused(PAUSE_REQUEST_EARLY_TERMINATION),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.tenant == ctx.accounts.tenant.key(),
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    require!(
+        lease.landlord_signed && lease.tenant_signed,
+        ZuviError::NotSigned
+    );
+    
+    require!(
+        !lease.early_termination_requested && lease.termination_proposer.is_none(),
+        ZuviError::TerminationAlreadyRequested
+    );
+    
+    // 須於通知期間之後、原租期結束之前生效
+    require!(
+        termination_date >= clock.unix_timestamp + (EARLY_TERMINATION_NOTICE_DAYS * SECONDS_PER_DAY),
+        ZuviError::InvalidDate
+    );
+    require!(
+        termination_date < lease.end_date,
+        ZuviError::InvalidDate
+    );
+    
+    lease.early_termination_requested = true;
+    lease.termination_date = termination_date;
+    lease.termination_penalty = 0;
+    
+    emit!(EarlyTerminationRequested {
+        lease: lease.key(),
+        tenant: lease.tenant,
+        termination_date,
+        requested_at: clock.unix_timestamp,
+    });
+    
+    msg!("已申請提前終止租約");
+    msg!("終止日: {}", termination_date);
+    msg!("等待房東接受");
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RequestEarlyTermination<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Account<'info, Lease>,
+    
+    pub tenant: Signer<'info>,
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, Transfer};
+use anchor_spl::token::Token;
 use anchor_spl::token_interface::TokenAccount;
-use crate::{constants::*, errors::*, events::DisputeResolved, state::*};
+use crate::{constants::*, errors::*, escrow_utils::EscrowUtils, events::DisputeResolved, state::*};
 
 pub fn resolve_dispute(
     ctx: Context<ResolveDispute>,
@@ -14,6 +14,11 @@ pub fn resolve_dispute(
     let escrow = &mut ctx.accounts.escrow;
     let dispute = &mut ctx.accounts.dispute;
     
+    require!(
+        !config.is_paused(PAUSE_RESOLVE_DISPUTE),
+        ZuviError::ProgramPaused
+    );
+    
     require!(
         ctx.accounts.arbitrator.key() == config.arbitrator,
         ZuviError::NotArbitrator
@@ -30,52 +35,35 @@ pub fn resolve_dispute(
         ZuviError::AmountMismatch
     );
     
-    let lease_key = lease.key();
-    let escrow_seeds = &[
-        ESCROW_SEED,
-        lease_key.as_ref(),
-        &[ctx.bumps.escrow],
-    ];
-    let signer_seeds = &[&escrow_seeds[..]];
-    
-    if landlord_amount > 0 {
-        token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.escrow_token.to_account_info(),
-                    to: ctx.accounts.landlord_token.to_account_info(),
-                    authority: escrow.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            landlord_amount,
-        )?;
-    }
-    
-    if tenant_amount > 0 {
-        token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.escrow_token.to_account_info(),
-                    to: ctx.accounts.tenant_token.to_account_info(),
-                    authority: escrow.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            tenant_amount,
-        )?;
-    }
+    EscrowUtils::transfer_from_escrow(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_token.to_account_info(),
+        ctx.accounts.landlord_token.to_account_info(),
+        escrow.to_account_info(),
+        lease.key(),
+        ctx.bumps.escrow,
+        landlord_amount,
+    )?;
+    
+    EscrowUtils::transfer_from_escrow(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_token.to_account_info(),
+        ctx.accounts.tenant_token.to_account_info(),
+        escrow.to_account_info(),
+        lease.key(),
+        ctx.bumps.escrow,
+        tenant_amount,
+    )?;
     
     escrow.status = ESCROW_STATUS_RELEASED;
     escrow.release_to_landlord = landlord_amount;
     escrow.release_to_tenant = tenant_amount;
     escrow.has_dispute = false;
     dispute.status = DISPUTE_STATUS_RESOLVED;
-    listing.status = LISTING_STATUS_AVAILABLE;
-    listing.current_tenant = None;
-    listing.has_active_lease = false;
+    // 租約已由其他流程結束時，房源可能已有新租約，不再重設
+    if lease.status == LEASE_STATUS_ACTIVE {
+        listing.release_lease(&lease.key());
+    }
     
     emit!(DisputeResolved {
         dispute: dispute.key(),
@@ -107,7 +95,7 @@ pub struct ResolveDispute<'info> {
     pub listing: Box<Account<'info, Listing>>,
     
     #[account(
-        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
         bump,
         constraint = lease.listing == listing.key()
     )]
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 房東於承租人簽署前設定共同承租人與租金分攤比例
+pub fn set_co_tenants(
+    ctx: Context<SetCoTenants>,
+    co_tenants: Vec<Pubkey>,
+    shares_bps: Vec<u16>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    
+    require!(
+        !config.is_paused(PAUSE_SET_CO_TENANTS),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.landlord == ctx.accounts.landlord.key(),
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    lease.amend_terms()?;
+    
+    require!(
+        co_tenants.len() <= MAX_CO_TENANTS && co_tenants.len() == shares_bps.len(),
+        ZuviError::InvalidParameter
+    );
+    
+    // 不可重複或包含主承租人
+    for (index, co_tenant) in co_tenants.iter().enumerate() {
+        require!(
+            *co_tenant != lease.tenant && !co_tenants[..index].contains(co_tenant),
+            ZuviError::InvalidParameter
+        );
+    }
+    
+    // 每人皆須分攤，且主承租人須保留部分比例
+    require!(
+        shares_bps.iter().all(|share| *share > 0),
+        ZuviError::InvalidParameter
+    );
+    let total_share_bps: u32 = shares_bps.iter().map(|share| *share as u32).sum();
+    require!(
+        total_share_bps < 10000,
+        ZuviError::InvalidParameter
+    );
+    
+    // 重新設定後所有共同承租人需重新簽署
+    lease.co_tenants = co_tenants
+        .iter()
+        .zip(shares_bps.iter())
+        .map(|(tenant, share_bps)| CoTenant {
+            tenant: *tenant,
+            share_bps: *share_bps,
+            signed: false,
+        })
+        .collect();
+    
+    emit!(CoTenantsSet {
+        lease: lease.key(),
+        landlord: lease.landlord,
+        co_tenants,
+        shares_bps,
+    });
+    
+    msg!("共同承租人已設定: {} 位", lease.co_tenants.len());
+    msg!("主承租人分攤: {} basis points", 10000 - total_share_bps);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCoTenants<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Account<'info, Lease>,
+    
+    pub landlord: Signer<'info>,
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 房東於承租人簽署前設定或移除保證人
+pub fn set_guarantor(ctx: Context<SetGuarantor>, guarantor: Option<Pubkey>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    
+    require!(
+        !config.is_paused(PAUSE_SET_GUARANTOR),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.landlord == ctx.accounts.landlord.key(),
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    lease.amend_terms()?;
+    
+    if let Some(guarantor_key) = guarantor {
+        require!(
+            guarantor_key != lease.tenant && guarantor_key != lease.landlord,
+            ZuviError::InvalidParameter
+        );
+    }
+    
+    // 變更保證人後需重新簽署
+    lease.guarantor = guarantor;
+    lease.guarantor_signed = false;
+    
+    emit!(GuarantorSet {
+        lease: lease.key(),
+        landlord: lease.landlord,
+        guarantor,
+    });
+    
+    match guarantor {
+        Some(guarantor_key) => msg!("保證人已設定: {}", guarantor_key),
+        None => msg!("保證人已移除"),
+    }
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGuarantor<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Account<'info, Lease>,
+    
+    pub landlord: Signer<'info>,
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 房東於承租人簽署前設定寬限期與滯納金
+pub fn set_late_fee_policy(
+    ctx: Context<SetLateFeePolicy>,
+    grace_days: u8,
+    late_fee_type: u8,
+    late_fee_value: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    
+    require!(
+        !config.is_paused(PAUSE_SET_LATE_FEE_POLICY),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.landlord == ctx.accounts.landlord.key(),
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    lease.amend_terms()?;
+    
+    require!(
+        grace_days <= lease.max_grace_days(),
+        ZuviError::InvalidParameter
+    );
+    
+    match late_fee_type {
+        LATE_FEE_TYPE_NONE => require!(late_fee_value == 0, ZuviError::InvalidParameter),
+        LATE_FEE_TYPE_FLAT => {}
+        LATE_FEE_TYPE_DAILY_BPS => require!(late_fee_value <= 10000, ZuviError::InvalidParameter),
+        _ => return Err(ZuviError::InvalidParameter.into()),
+    }
+    
+    lease.grace_days = grace_days;
+    lease.late_fee_type = late_fee_type;
+    lease.late_fee_value = late_fee_value;
+    
+    emit!(LateFeePolicySet {
+        lease: lease.key(),
+        landlord: lease.landlord,
+        grace_days,
+        late_fee_type,
+        late_fee_value,
+    });
+    
+    msg!("滯納金規則已設定");
+    msg!("寬限期: {} 天", grace_days);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetLateFeePolicy<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Account<'info, Lease>,
+    
+    pub landlord: Signer<'info>,
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 房東設定或移除房源的受託管理人及其權限
+pub fn set_listing_manager(
+    ctx: Context<SetListingManager>,
+    manager: Option<Pubkey>,
+    permissions: u8,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let listing = &mut ctx.accounts.listing;
+    
+    // 確認功能未暫停
+    require!(
+        !config.is_paused(PAUSE_SET_LISTING_MANAGER),
+        ZuviError::ProgramPaused
+    );
+    
+    // 僅房東本人可指定管理人
+    require!(
+        listing.owner == ctx.accounts.owner.key(),
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        permissions & !MANAGER_PERMISSION_ALL == 0,
+        ZuviError::InvalidParameter
+    );
+    
+    match manager {
+        Some(manager_key) => require!(
+            manager_key != listing.owner,
+            ZuviError::InvalidParameter
+        ),
+        None => require!(permissions == 0, ZuviError::InvalidParameter),
+    }
+    
+    listing.manager = manager;
+    listing.manager_permissions = permissions;
+    
+    emit!(ListingManagerSet {
+        listing: listing.key(),
+        owner: listing.owner,
+        manager,
+        permissions,
+    });
+    
+    match manager {
+        Some(manager_key) => msg!("管理人已設定: {}，權限: {:#06b}", manager_key, permissions),
+        None => msg!("管理人已移除"),
+    }
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetListingManager<'info> {
+    /// 系統配置
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// 房源列表帳戶
+    #[account(
+        mut,
+        seeds = [LISTING_SEED, listing.property_attest.as_ref()],
+        bump
+    )]
+    pub listing: Account<'info, Listing>,
+    
+    /// 房東
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 設定緊急暫停旗標
+pub fn set_pause(
+    ctx: Context<SetPause>,
+    paused: u64,
+    allow_exit_when_paused: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    // 確認是守護者
+    require!(
+        config.guardian == ctx.accounts.guardian.key(),
+        ZuviError::NotGuardian
+    );
+    
+    config.paused = paused;
+    config.allow_exit_when_paused = allow_exit_when_paused;
+    
+    emit!(PauseChanged {
+        config: config.key(),
+        guardian: config.guardian,
+        paused,
+        allow_exit_when_paused,
+    });
+    
+    msg!("暫停狀態已更新");
+    msg!("暫停旗標: {:#x}", paused);
+    msg!("押金釋放路徑: {}", if allow_exit_when_paused { "保持開放" } else { "一併暫停" });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    /// 系統配置帳戶
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// 守護者
+    pub guardian: Signer<'info>,
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 房東於承租人簽署前設定繳費週期、繳費日與計算到期日的時區
+pub fn set_payment_schedule(
+    ctx: Context<SetPaymentSchedule>,
+    payment_frequency: u8,
+    payment_day: u8,
+    utc_offset_minutes: i16,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    
+    require!(
+        !config.is_paused(PAUSE_SET_PAYMENT_SCHEDULE),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.landlord == ctx.accounts.landlord.key(),
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    lease.amend_terms()?;
+    
+    // 週繳以星期幾為繳費日，其餘週期以每月幾號為繳費日
+    let max_payment_day = match payment_frequency {
+        PAYMENT_FREQUENCY_WEEKLY => MAX_WEEKLY_PAYMENT_DAY,
+        PAYMENT_FREQUENCY_MONTHLY | PAYMENT_FREQUENCY_QUARTERLY | PAYMENT_FREQUENCY_YEARLY => MAX_PAYMENT_DAY,
+        _ => return Err(ZuviError::InvalidParameter.into()),
+    };
+    require!(
+        (MIN_PAYMENT_DAY..=max_payment_day).contains(&payment_day),
+        ZuviError::InvalidPaymentDay
+    );
+    
+    require!(
+        (MIN_UTC_OFFSET_MINUTES..=MAX_UTC_OFFSET_MINUTES).contains(&utc_offset_minutes),
+        ZuviError::InvalidParameter
+    );
+    
+    lease.payment_frequency = payment_frequency;
+    lease.payment_day = payment_day;
+    lease.utc_offset_minutes = utc_offset_minutes;
+    
+    // 確認新排程下整個租期的到期日皆可計算
+    lease.total_payments_until(lease.end_date)?;
+    
+    // 已設定的寬限期不得跨越新週期的下一期
+    require!(
+        lease.grace_days <= lease.max_grace_days(),
+        ZuviError::InvalidParameter
+    );
+    
+    emit!(PaymentScheduleSet {
+        lease: lease.key(),
+        landlord: lease.landlord,
+        payment_frequency,
+        payment_day,
+        utc_offset_minutes,
+    });
+    
+    msg!("繳費週期已設定: {}", payment_frequency);
+    msg!("繳費日: {}", payment_day);
+    msg!("時區: UTC{:+} 分鐘", utc_offset_minutes);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPaymentSchedule<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Account<'info, Lease>,
+    
+    pub landlord: Signer<'info>,
+}
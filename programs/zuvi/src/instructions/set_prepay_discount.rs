@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 房東設定預付未到期租金的折扣
+pub fn set_prepay_discount(ctx: Context<SetPrepayDiscount>, discount_bps: u16) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    
+    require!(
+        !config.is_paused(PAUSE_SET_PREPAY_DISCOUNT),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.landlord == ctx.accounts.landlord.key(),
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    require!(
+        discount_bps <= MAX_PREPAY_DISCOUNT_BPS,
+        ZuviError::InvalidParameter
+    );
+    
+    lease.prepay_discount_bps = discount_bps;
+    
+    emit!(PrepayDiscountSet {
+        lease: lease.key(),
+        landlord: lease.landlord,
+        discount_bps,
+    });
+    
+    msg!("預付折扣已設定為: {} basis points", discount_bps);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPrepayDiscount<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Account<'info, Lease>,
+    
+    pub landlord: Signer<'info>,
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 房東於承租人簽署前設定年度租金調漲
+pub fn set_rent_escalation(
+    ctx: Context<SetRentEscalation>,
+    escalation_bps: u16,
+    escalation_cap_bps: u16,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    
+    require!(
+        !config.is_paused(PAUSE_SET_RENT_ESCALATION),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.landlord == ctx.accounts.landlord.key(),
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    lease.amend_terms()?;
+    
+    require!(
+        escalation_bps <= MAX_RENT_ESCALATION_BPS,
+        ZuviError::InvalidParameter
+    );
+    
+    // 累計上限不得低於單次調漲幅度
+    require!(
+        escalation_cap_bps <= MAX_ESCALATION_CAP_BPS
+            && (escalation_bps == 0 || escalation_cap_bps >= escalation_bps),
+        ZuviError::InvalidParameter
+    );
+    
+    lease.escalation_bps = escalation_bps;
+    lease.escalation_cap_bps = escalation_cap_bps;
+    
+    emit!(RentEscalationSet {
+        lease: lease.key(),
+        landlord: lease.landlord,
+        escalation_bps,
+        escalation_cap_bps,
+    });
+    
+    msg!("租金調漲已設定");
+    msg!("每年調漲: {} basis points", escalation_bps);
+    msg!("累計上限: {} basis points", escalation_cap_bps);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRentEscalation<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Account<'info, Lease>,
+    
+    pub landlord: Signer<'info>,
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 承租人設定可代為支付租金的第三方（雇主、租金補貼單位等）
+pub fn set_rent_payers(
+    ctx: Context<SetRentPayers>,
+    payer_restricted: bool,
+    approved_payers: Vec<Pubkey>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    
+    require!(
+        !config.is_paused(PAUSE_SET_RENT_PAYERS),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.tenant == ctx.accounts.tenant.key(),
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    require!(
+        approved_payers.len() <= MAX_APPROVED_PAYERS,
+        ZuviError::InvalidParameter
+    );
+    
+    // 不可重複或包含承租人本人
+    for (index, payer) in approved_payers.iter().enumerate() {
+        require!(
+            *payer != lease.tenant && !approved_payers[..index].contains(payer),
+            ZuviError::InvalidParameter
+        );
+    }
+    
+    lease.payer_restricted = payer_restricted;
+    lease.approved_payers = approved_payers;
+    
+    emit!(RentPayersSet {
+        lease: lease.key(),
+        tenant: lease.tenant,
+        payer_restricted,
+        approved_payers: lease.approved_payers.clone(),
+    });
+    
+    msg!("代繳人設定已更新");
+    msg!("僅限指定代繳人: {}", payer_restricted);
+    msg!("指定代繳人數: {}", lease.approved_payers.len());
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRentPayers<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Account<'info, Lease>,
+    
+    pub tenant: Signer<'info>,
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Approve, Token};
+use anchor_spl::token_interface::TokenAccount;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 保證人於承租人簽署前簽署保證，並核准租約 PDA 於欠租時代償的額度
+pub fn sign_guarantee(ctx: Context<SignGuarantee>, guarantee_cap: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    let guarantor_key = ctx.accounts.guarantor.key();
+    
+    require!(
+        !config.is_paused(PAUSE_SIGN_GUARANTEE),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.guarantor == Some(guarantor_key),
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    require!(
+        lease.landlord_signed,
+        ZuviError::NotSigned
+    );
+    
+    require!(
+        !lease.tenant_signed && !lease.guarantor_signed,
+        ZuviError::AlreadySigned
+    );
+    
+    require!(guarantee_cap > 0, ZuviError::InvalidParameter);
+    
+    token::approve(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Approve {
+                to: ctx.accounts.guarantor_token.to_account_info(),
+                delegate: lease.to_account_info(),
+                authority: ctx.accounts.guarantor.to_account_info(),
+            },
+        ),
+        guarantee_cap,
+    )?;
+    
+    lease.guarantor_signed = true;
+    
+    emit!(GuaranteeSigned {
+        lease: lease.key(),
+        guarantor: guarantor_key,
+        guarantee_cap,
+    });
+    
+    msg!("保證人已簽署");
+    msg!("代償額度: {} USDC", guarantee_cap);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SignGuarantee<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Box<Account<'info, Lease>>,
+    
+    pub guarantor: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = guarantor_token.owner == guarantor.key(),
+        constraint = guarantor_token.mint == config.usdc_mint
+    )]
+    pub guarantor_token: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
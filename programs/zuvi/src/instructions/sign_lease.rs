@@ -1,12 +1,21 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, Transfer};
+use anchor_spl::token::{self, Approve, Token, Transfer};
 use anchor_spl::token_interface::{Mint, TokenAccount};
 use crate::{constants::*, errors::*, events::*, state::*};
 
-pub fn sign_lease(ctx: Context<SignLease>) -> Result<()> {
+/// 主承租人簽署租約（共同承租人須已簽署）
+/// terms_revision 為承租人審閱時的條款版本，簽署前條款若已修改則拒絕
+/// 指定 auto_debit_cap 時同時核准租約 PDA 自動扣款的額度
+pub fn sign_lease(ctx: Context<SignLease>, terms_revision: u32, auto_debit_cap: Option<u64>) -> Result<()> {
     let config = &ctx.accounts.config;
     let listing = &mut ctx.accounts.listing;
     let lease = &mut ctx.accounts.lease;
+    let clock = Clock::get()?;
+    
+    require!(
+        !config.is_paused(PAUSE_SIGN_LEASE),
+        ZuviError::ProgramPaused
+    );
     
     require!(
         lease.tenant == ctx.accounts.tenant.key(),
@@ -23,10 +32,30 @@ pub fn sign_lease(ctx: Context<SignLease>) -> Result<()> {
         ZuviError::NotSigned
     );
     
-    let platform_fee = lease.rent
-        .checked_mul(config.fee_rate as u64).unwrap()
+    require!(
+        lease.terms_revision == terms_revision,
+        ZuviError::TermsChanged
+    );
+    
+    require!(
+        lease.co_tenants.iter().all(|co_tenant| co_tenant.signed),
+        ZuviError::CoTenantsNotSigned
+    );
+    
+    require!(
+        lease.guarantor.is_none() || lease.guarantor_signed,
+        ZuviError::GuarantorNotSigned
+    );
+    
+    // 鎖定簽約當下生效的費率，後續費率變更不影響本租約
+    lease.fee_rate = config.effective_fee_rate(clock.unix_timestamp);
+    
+    // 首期租金按起租日至首個繳費日的天數計算
+    let first_rent = lease.period_rent(0)?;
+    let platform_fee = first_rent
+        .checked_mul(lease.fee_rate as u64).unwrap()
         .checked_div(10000).unwrap();
-    let landlord_rent = lease.rent - platform_fee;
+    let landlord_rent = first_rent - platform_fee;
     
     token::transfer(
         CpiContext::new(
@@ -66,7 +95,28 @@ pub fn sign_lease(ctx: Context<SignLease>) -> Result<()> {
     
     lease.tenant_signed = true;
     lease.paid_months = 1;
-    lease.last_payment = Clock::get()?.unix_timestamp;
+    lease.last_payment = clock.unix_timestamp;
+    
+    if let Some(auto_debit_cap) = auto_debit_cap.filter(|cap| *cap > 0) {
+        token::approve(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Approve {
+                    to: ctx.accounts.tenant_token.to_account_info(),
+                    delegate: lease.to_account_info(),
+                    authority: ctx.accounts.tenant.to_account_info(),
+                },
+            ),
+            auto_debit_cap,
+        )?;
+        lease.auto_debit_cap = auto_debit_cap;
+        
+        emit!(AutoDebitSet {
+            lease: lease.key(),
+            tenant: lease.tenant,
+            auto_debit_cap,
+        });
+    }
     
     let escrow = &mut ctx.accounts.escrow;
     escrow.lease = lease.key();
@@ -80,7 +130,10 @@ pub fn sign_lease(ctx: Context<SignLease>) -> Result<()> {
     
     listing.status = LISTING_STATUS_RENTED;
     listing.current_tenant = Some(lease.tenant);
+    listing.current_lease = Some(lease.key());
     listing.has_active_lease = true;
+    // 核准的申請已成為生效租約
+    listing.has_approved_application = false;
     
     emit!(LeaseSigned {
         lease: lease.key(),
@@ -89,12 +142,15 @@ pub fn sign_lease(ctx: Context<SignLease>) -> Result<()> {
         tenant: lease.tenant,
         escrow: escrow.key(),
         deposit_amount: lease.deposit,
-        first_rent_paid: lease.rent,
+        first_rent_paid: first_rent,
     });
     
     msg!("租約已生效");
     msg!("押金 {} USDC 已託管", lease.deposit);
-    msg!("首期租金已支付");
+    msg!("首期租金 {} USDC 已支付", first_rent);
+    if lease.auto_debit_cap > 0 {
+        msg!("已啟用自動扣款，額度: {} USDC", lease.auto_debit_cap);
+    }
     
     Ok(())
 }
@@ -109,7 +165,7 @@ pub struct SignLease<'info> {
     
     #[account(
         mut,
-        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
         bump,
         constraint = lease.listing == listing.key()
     )]
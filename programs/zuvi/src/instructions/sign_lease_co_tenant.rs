@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 共同承租人於主承租人簽署前簽署租約
+pub fn sign_lease_co_tenant(ctx: Context<SignLeaseCoTenant>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let lease = &mut ctx.accounts.lease;
+    let co_tenant_key = ctx.accounts.co_tenant.key();
+    
+    require!(
+        !config.is_paused(PAUSE_SIGN_LEASE_CO_TENANT),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    require!(
+        lease.landlord_signed,
+        ZuviError::NotSigned
+    );
+    
+    require!(
+        !lease.tenant_signed,
+        ZuviError::AlreadySigned
+    );
+    
+    let co_tenant = lease.co_tenants
+        .iter_mut()
+        .find(|co_tenant| co_tenant.tenant == co_tenant_key)
+        .ok_or(ZuviError::NotCoTenant)?;
+    
+    require!(
+        !co_tenant.signed,
+        ZuviError::AlreadySigned
+    );
+    
+    co_tenant.signed = true;
+    
+    emit!(CoTenantSigned {
+        lease: lease.key(),
+        co_tenant: co_tenant_key,
+    });
+    
+    msg!("共同承租人已簽署");
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SignLeaseCoTenant<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Account<'info, Lease>,
+    
+    pub co_tenant: Signer<'info>,
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 房東因承租人欠租終止租約，並凍結押金待結算
+pub fn terminate_for_arrears(ctx: Context<TerminateForArrears>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let listing = &mut ctx.accounts.listing;
+    let lease = &mut ctx.accounts.lease;
+    let escrow = &mut ctx.accounts.escrow;
+    let clock = Clock::get()?;
+    
+    require!(
+        !config.is_paused(PAUSE_TERMINATE_FOR_ARREARS),
+        ZuviError::ProgramPaused
+    );
+    
+    require!(
+        lease.landlord == ctx.accounts.landlord.key(),
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        lease.status == LEASE_STATUS_ACTIVE,
+        ZuviError::LeaseNotActive
+    );
+    
+    require!(
+        lease.landlord_signed && lease.tenant_signed,
+        ZuviError::NotSigned
+    );
+    
+    require!(
+        escrow.status != ESCROW_STATUS_RELEASED,
+        ZuviError::DepositAlreadyReleased
+    );
+    
+    // 計算欠租期數（不超過整個租期應付期數）
+    let total_payments = lease.total_payments_until(lease.end_date)?;
+    let payments_due = lease.payments_due(clock.unix_timestamp)?.min(total_payments);
+    let arrears_periods = payments_due.saturating_sub(lease.paid_months);
+    
+    // 門檻以月計，依繳費週期將欠租期數換算為月數後比較
+    require!(
+        arrears_periods as u64 * 12
+            >= config.arrears_termination_months as u64 * lease.periods_per_year() as u64,
+        ZuviError::ArrearsBelowThreshold
+    );
+    
+    let arrears_amount = (lease.paid_months..payments_due)
+        .map(|month| lease.period_rent(month))
+        .sum::<Result<u64>>()?;
+    
+    lease.status = LEASE_STATUS_TERMINATED;
+    lease.termination_date = clock.unix_timestamp;
+    listing.release_lease(&lease.key());
+    
+    // 凍結押金，取消進行中的結算
+    escrow.status = ESCROW_STATUS_FROZEN;
+    escrow.release_to_landlord = 0;
+    escrow.release_to_tenant = 0;
+    escrow.landlord_signed = false;
+    escrow.tenant_signed = false;
+    
+    emit!(LeaseTerminatedForArrears {
+        lease: lease.key(),
+        listing: listing.key(),
+        escrow: escrow.key(),
+        landlord: lease.landlord,
+        tenant: lease.tenant,
+        arrears_periods,
+        arrears_amount,
+        terminated_at: clock.unix_timestamp,
+    });
+    
+    msg!("租約因欠租終止");
+    msg!("欠租期數: {}", arrears_periods);
+    msg!("欠租金額: {} USDC", arrears_amount);
+    msg!("押金已凍結待結算");
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TerminateForArrears<'info> {
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        mut,
+        seeds = [LISTING_SEED, listing.property_attest.as_ref()],
+        bump
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+    
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump,
+        constraint = lease.listing == listing.key()
+    )]
+    pub lease: Box<Account<'info, Lease>>,
+    
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, lease.key().as_ref()],
+        bump,
+        constraint = escrow.lease == lease.key()
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    
+    pub landlord: Signer<'info>,
+}
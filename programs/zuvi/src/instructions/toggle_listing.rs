@@ -3,11 +3,18 @@ use crate::{constants::*, errors::*, events::*, state::*};
 
 /// 切換房源狀態（上架/下架）
 pub fn toggle_listing(ctx: Context<ToggleListing>) -> Result<()> {
+    let config = &ctx.accounts.config;
     let listing = &mut ctx.accounts.listing;
     
-    // 確認是房東本人
+    // 確認功能未暫停
     require!(
-        listing.owner == ctx.accounts.owner.key(),
+        !config.is_paused(PAUSE_TOGGLE_LISTING),
+        ZuviError::ProgramPaused
+    );
+    
+    // 確認是房東本人或具權限的管理人
+    require!(
+        listing.is_authorized(&ctx.accounts.owner.key(), MANAGER_PERMISSION_TOGGLE_LISTING),
         ZuviError::Unauthorized
     );
     
@@ -38,6 +45,13 @@ pub fn toggle_listing(ctx: Context<ToggleListing>) -> Result<()> {
 
 #[derive(Accounts)]
 pub struct ToggleListing<'info> {
+    /// 系統配置
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
     /// 房源列表帳戶
     #[account(
         mut,
@@ -46,6 +60,6 @@ pub struct ToggleListing<'info> {
     )]
     pub listing: Account<'info, Listing>,
     
-    /// 房東
+    /// 房東或具上下架權限的管理人
     pub owner: Signer<'info>,
 }
\ No newline at end of file
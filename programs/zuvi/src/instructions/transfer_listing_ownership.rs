@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 房屋出售時移轉房源所有權，生效中的租約由買方承受（買賣不破租賃）
+pub fn transfer_listing_ownership(ctx: Context<TransferListingOwnership>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let listing = &mut ctx.accounts.listing;
+    let seller = ctx.accounts.seller.key();
+    let buyer = ctx.accounts.buyer.key();
+    
+    // 確認功能未暫停
+    require!(
+        !config.is_paused(PAUSE_TRANSFER_LISTING_OWNERSHIP),
+        ZuviError::ProgramPaused
+    );
+    
+    // 驗證 API 簽名者（已核發買方的新產權憑證）
+    require!(
+        ctx.accounts.api_signer.key() == config.api_signer,
+        ZuviError::ApiSignatureRequired
+    );
+    
+    require!(
+        listing.owner == seller,
+        ZuviError::Unauthorized
+    );
+    
+    require!(
+        buyer != seller,
+        ZuviError::InvalidParameter
+    );
+    
+    // 買方須提供新核發的產權憑證，不可沿用原憑證
+    let owner_attest = ctx.accounts.owner_attest.key();
+    require!(
+        owner_attest != listing.owner_attest
+            && owner_attest != listing.property_attest
+            && owner_attest != Pubkey::default(),
+        ZuviError::InvalidParameter
+    );
+    
+    // 已核准申請或待簽署的租約仍以原房東為出租人，須先取消或簽署
+    require!(
+        !listing.has_approved_application,
+        ZuviError::InvalidParameter
+    );
+    
+    // 有生效中的租約時必須一併移轉
+    require!(
+        !listing.has_active_lease || ctx.accounts.lease.is_some(),
+        ZuviError::InvalidParameter
+    );
+    
+    let mut transferred_lease = None;
+    if let Some(lease) = ctx.accounts.lease.as_mut() {
+        require!(
+            lease.listing == listing.key() && lease.landlord == seller,
+            ZuviError::InvalidParameter
+        );
+        
+        require!(
+            lease.status == LEASE_STATUS_ACTIVE,
+            ZuviError::LeaseNotActive
+        );
+        
+        // 必須為房源目前已簽署生效的租約
+        require!(
+            lease.tenant_signed && listing.current_lease == Some(lease.key()),
+            ZuviError::InvalidParameter
+        );
+        
+        // 押金不可處於結算或爭議中
+        let escrow = ctx.accounts.escrow.as_ref().ok_or(ZuviError::InvalidParameter)?;
+        require!(
+            escrow.lease == lease.key(),
+            ZuviError::InvalidParameter
+        );
+        require!(
+            escrow.status == ESCROW_STATUS_HOLDING && !escrow.has_dispute,
+            ZuviError::InvalidParameter
+        );
+        
+        lease.landlord = buyer;
+        
+        // 撤回原房東提出、尚未回應的續約與合意終止提議
+        lease.renewal_end_date = 0;
+        lease.renewal_rent = 0;
+        if lease.termination_proposer == Some(seller) {
+            lease.termination_proposer = None;
+            lease.termination_date = 0;
+            lease.termination_refund = 0;
+        }
+        
+        transferred_lease = Some(lease.key());
+    }
+    
+    listing.owner = buyer;
+    listing.owner_attest = owner_attest;
+    // 原房東指定的管理人不再有效
+    listing.manager = None;
+    listing.manager_permissions = 0;
+    
+    emit!(ListingOwnershipTransferred {
+        listing: listing.key(),
+        previous_owner: seller,
+        new_owner: buyer,
+        owner_attest: listing.owner_attest,
+        lease: transferred_lease,
+    });
+    
+    msg!("房源所有權已移轉");
+    msg!("原房東: {}", seller);
+    msg!("新房東: {}", buyer);
+    if let Some(lease_key) = transferred_lease {
+        msg!("租約已由新房東承受: {}", lease_key);
+    }
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferListingOwnership<'info> {
+    /// 系統配置
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    /// 房源列表帳戶
+    #[account(
+        mut,
+        seeds = [LISTING_SEED, listing.property_attest.as_ref()],
+        bump
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+    
+    /// 生效中的租約（如果有）
+    #[account(
+        mut,
+        seeds = [LEASE_SEED, lease.listing.as_ref(), lease.original_tenant.as_ref(), &lease.start_date.to_le_bytes()],
+        bump
+    )]
+    pub lease: Option<Box<Account<'info, Lease>>>,
+    
+    /// 租約的押金託管帳戶（有租約時必填）
+    pub escrow: Option<Box<Account<'info, Escrow>>>,
+    
+    /// 原房東
+    pub seller: Signer<'info>,
+    
+    /// 新房東
+    pub buyer: Signer<'info>,
+    
+    /// API 簽名者
+    pub api_signer: Signer<'info>,
+    
+    /// 買方的新產權憑證帳戶
+    /// CHECK: 由 API 驗證
+    pub owner_attest: AccountInfo<'info>,
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, events::*, state::*};
+
+/// 更新系統配置
+pub fn update_config(
+    ctx: Context<UpdateConfig>,
+    api_signer: Option<Pubkey>,
+    arbitrator: Option<Pubkey>,
+    fee_receiver: Option<Pubkey>,
+    fee_rate: Option<u16>,
+    guardian: Option<Pubkey>,
+    arrears_termination_months: Option<u8>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+    
+    // 確認系統已初始化
+    require!(
+        config.initialized,
+        ZuviError::NotInitialized
+    );
+    
+    // 確認是管理員
+    require!(
+        config.admin == ctx.accounts.admin.key(),
+        ZuviError::NotAdmin
+    );
+    
+    // 更新 API 簽名者
+    if let Some(new_api_signer) = api_signer {
+        config.api_signer = new_api_signer;
+        msg!("API Signer 已更新為: {}", new_api_signer);
+    }
+    
+    // 更新仲裁者
+    if let Some(new_arbitrator) = arbitrator {
+        config.arbitrator = new_arbitrator;
+        msg!("仲裁者已更新為: {}", new_arbitrator);
+    }
+    
+    // 更新費用接收者
+    if let Some(new_fee_receiver) = fee_receiver {
+        config.fee_receiver = new_fee_receiver;
+        msg!("費用接收者已更新為: {}", new_fee_receiver);
+    }
+    
+    // 更新守護者
+    if let Some(new_guardian) = guardian {
+        config.guardian = new_guardian;
+        msg!("守護者已更新為: {}", new_guardian);
+    }
+    
+    // 更新欠租終止門檻
+    if let Some(new_months) = arrears_termination_months {
+        require!(new_months > 0, ZuviError::InvalidParameter);
+        config.arrears_termination_months = new_months;
+        msg!("欠租終止門檻已更新為: {} 個月", new_months);
+    }
+    
+    // 先套用已到期的排程費率
+    config.apply_pending_fee_rate(clock.unix_timestamp);
+    
+    // 排程新費率，延遲後才生效
+    let mut fee_rate_effective_at = None;
+    if let Some(new_fee_rate) = fee_rate {
+        require!(
+            new_fee_rate <= MAX_FEE_RATE,
+            ZuviError::InvalidFeeRate
+        );
+        let effective_at = clock.unix_timestamp + (MIN_FEE_RATE_DELAY_DAYS * SECONDS_PER_DAY);
+        config.pending_fee_rate = Some(new_fee_rate);
+        config.fee_rate_effective_at = effective_at;
+        fee_rate_effective_at = Some(effective_at);
+        msg!("費率將於 {} 更新為: {} basis points", effective_at, new_fee_rate);
+    }
+    
+    emit!(ConfigUpdated {
+        config: config.key(),
+        admin: config.admin,
+        api_signer,
+        arbitrator,
+        fee_receiver,
+        fee_rate,
+        fee_rate_effective_at,
+        guardian,
+        arrears_termination_months,
+    });
+    
+    msg!("系統配置更新成功");
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    /// 系統配置帳戶
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// 管理員
+    pub admin: Signer<'info>,
+}
@@ -8,11 +8,18 @@ pub fn update_listing(
     deposit: Option<u64>,
     metadata_uri: Option<[u8; 64]>,
 ) -> Result<()> {
+    let config = &ctx.accounts.config;
     let listing = &mut ctx.accounts.listing;
     
-    // 確認是房東本人
+    // 確認功能未暫停
     require!(
-        listing.owner == ctx.accounts.owner.key(),
+        !config.is_paused(PAUSE_UPDATE_LISTING),
+        ZuviError::ProgramPaused
+    );
+    
+    // 確認是房東本人或具權限的管理人
+    require!(
+        listing.is_authorized(&ctx.accounts.owner.key(), MANAGER_PERMISSION_UPDATE_LISTING),
         ZuviError::Unauthorized
     );
     
@@ -69,6 +76,13 @@ pub fn update_listing(
 
 #[derive(Accounts)]
 pub struct UpdateListing<'info> {
+    /// 系統配置
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
     /// 房源列表帳戶
     #[account(
         mut,
@@ -77,6 +91,6 @@ pub struct UpdateListing<'info> {
     )]
     pub listing: Account<'info, Listing>,
     
-    /// 房東
+    /// 房東或具更新權限的管理人
     pub owner: Signer<'info>,
 }
\ No newline at end of file
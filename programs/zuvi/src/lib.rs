@@ -1,7 +1,13 @@
+// Anchor 0.31 的 #[program] 會在 crate 根層產生 __private::__idl 模組，其中的 IDL 指令
+// 呼叫已棄用的 AccountInfo::realloc；該模組並非 mod zuvi 的一部分，也不受 no-idl 功能影響，
+// 無法在產生的項目上個別標註，只能於 crate 層級允許
+#![allow(deprecated)]
+
 use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod errors;
+pub mod escrow_utils;
 pub mod events;
 pub mod instructions;
 pub mod state;
@@ -26,6 +32,42 @@ pub mod zuvi {
         instructions::initialize(ctx, api_signer, arbitrator, fee_receiver, usdc_mint, fee_rate)
     }
 
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        api_signer: Option<Pubkey>,
+        arbitrator: Option<Pubkey>,
+        fee_receiver: Option<Pubkey>,
+        fee_rate: Option<u16>,
+        guardian: Option<Pubkey>,
+        arrears_termination_months: Option<u8>,
+    ) -> Result<()> {
+        instructions::update_config(
+            ctx,
+            api_signer,
+            arbitrator,
+            fee_receiver,
+            fee_rate,
+            guardian,
+            arrears_termination_months,
+        )
+    }
+
+    pub fn set_pause(ctx: Context<SetPause>, paused: u64, allow_exit_when_paused: bool) -> Result<()> {
+        instructions::set_pause(ctx, paused, allow_exit_when_paused)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::propose_admin(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin(ctx)
+    }
+
+    pub fn cancel_admin_transfer(ctx: Context<CancelAdminTransfer>) -> Result<()> {
+        instructions::cancel_admin_transfer(ctx)
+    }
+
     pub fn create_listing(
         ctx: Context<CreateListing>,
         address: [u8; 64],
@@ -50,6 +92,18 @@ pub mod zuvi {
         instructions::toggle_listing(ctx)
     }
 
+    pub fn set_listing_manager(
+        ctx: Context<SetListingManager>,
+        manager: Option<Pubkey>,
+        permissions: u8,
+    ) -> Result<()> {
+        instructions::set_listing_manager(ctx, manager, permissions)
+    }
+
+    pub fn transfer_listing_ownership(ctx: Context<TransferListingOwnership>) -> Result<()> {
+        instructions::transfer_listing_ownership(ctx)
+    }
+
     pub fn apply_lease(ctx: Context<ApplyLease>, message_uri: [u8; 64], created_at: i64) -> Result<()> {
         instructions::apply_lease(ctx, message_uri, created_at)
     }
@@ -82,12 +136,138 @@ pub mod zuvi {
         instructions::create_lease(ctx, applicant, _application_created_at, start_date, end_date, payment_day, contract_uri)
     }
 
-    pub fn sign_lease(ctx: Context<SignLease>) -> Result<()> {
-        instructions::sign_lease(ctx)
+    pub fn set_rent_escalation(
+        ctx: Context<SetRentEscalation>,
+        escalation_bps: u16,
+        escalation_cap_bps: u16,
+    ) -> Result<()> {
+        instructions::set_rent_escalation(ctx, escalation_bps, escalation_cap_bps)
     }
 
-    pub fn pay_rent(ctx: Context<PayRent>) -> Result<()> {
-        instructions::pay_rent(ctx)
+    pub fn set_late_fee_policy(
+        ctx: Context<SetLateFeePolicy>,
+        grace_days: u8,
+        late_fee_type: u8,
+        late_fee_value: u64,
+    ) -> Result<()> {
+        instructions::set_late_fee_policy(ctx, grace_days, late_fee_type, late_fee_value)
+    }
+
+    pub fn set_payment_schedule(
+        ctx: Context<SetPaymentSchedule>,
+        payment_frequency: u8,
+        payment_day: u8,
+        utc_offset_minutes: i16,
+    ) -> Result<()> {
+        instructions::set_payment_schedule(ctx, payment_frequency, payment_day, utc_offset_minutes)
+    }
+
+    pub fn set_co_tenants(
+        ctx: Context<SetCoTenants>,
+        co_tenants: Vec<Pubkey>,
+        shares_bps: Vec<u16>,
+    ) -> Result<()> {
+        instructions::set_co_tenants(ctx, co_tenants, shares_bps)
+    }
+
+    pub fn sign_lease_co_tenant(ctx: Context<SignLeaseCoTenant>) -> Result<()> {
+        instructions::sign_lease_co_tenant(ctx)
+    }
+
+    pub fn set_guarantor(ctx: Context<SetGuarantor>, guarantor: Option<Pubkey>) -> Result<()> {
+        instructions::set_guarantor(ctx, guarantor)
+    }
+
+    pub fn sign_guarantee(ctx: Context<SignGuarantee>, guarantee_cap: u64) -> Result<()> {
+        instructions::sign_guarantee(ctx, guarantee_cap)
+    }
+
+    pub fn sign_lease(ctx: Context<SignLease>, terms_revision: u32, auto_debit_cap: Option<u64>) -> Result<()> {
+        instructions::sign_lease(ctx, terms_revision, auto_debit_cap)
+    }
+
+    pub fn enable_auto_debit(ctx: Context<EnableAutoDebit>, auto_debit_cap: u64) -> Result<()> {
+        instructions::enable_auto_debit(ctx, auto_debit_cap)
+    }
+
+    pub fn pay_rent(ctx: Context<PayRent>, months: u32) -> Result<()> {
+        instructions::pay_rent(ctx, months)
+    }
+
+    pub fn pay_rent_share(ctx: Context<PayRent>, for_tenant: Pubkey) -> Result<()> {
+        instructions::pay_rent_share(ctx, for_tenant)
+    }
+
+    pub fn collect_rent(ctx: Context<CollectRent>) -> Result<()> {
+        instructions::collect_rent(ctx)
+    }
+
+    pub fn set_rent_payers(
+        ctx: Context<SetRentPayers>,
+        payer_restricted: bool,
+        approved_payers: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_rent_payers(ctx, payer_restricted, approved_payers)
+    }
+
+    pub fn prepay_rent(ctx: Context<PayRent>, months: u32) -> Result<()> {
+        instructions::prepay_rent(ctx, months)
+    }
+
+    pub fn set_prepay_discount(ctx: Context<SetPrepayDiscount>, discount_bps: u16) -> Result<()> {
+        instructions::set_prepay_discount(ctx, discount_bps)
+    }
+
+    pub fn complete_lease(ctx: Context<CompleteLease>) -> Result<()> {
+        instructions::complete_lease(ctx)
+    }
+
+    pub fn request_early_termination(ctx: Context<RequestEarlyTermination>, termination_date: i64) -> Result<()> {
+        instructions::request_early_termination(ctx, termination_date)
+    }
+
+    pub fn accept_early_termination(ctx: Context<AcceptEarlyTermination>, penalty: Option<u64>) -> Result<()> {
+        instructions::accept_early_termination(ctx, penalty)
+    }
+
+    pub fn cancel_early_termination(ctx: Context<CancelEarlyTermination>) -> Result<()> {
+        instructions::cancel_early_termination(ctx)
+    }
+
+    pub fn terminate_for_arrears(ctx: Context<TerminateForArrears>) -> Result<()> {
+        instructions::terminate_for_arrears(ctx)
+    }
+
+    pub fn claim_from_guarantor(ctx: Context<ClaimFromGuarantor>) -> Result<()> {
+        instructions::claim_from_guarantor(ctx)
+    }
+
+    pub fn migrate_tenant(ctx: Context<MigrateTenant>) -> Result<()> {
+        instructions::migrate_tenant(ctx)
+    }
+
+    pub fn propose_termination(
+        ctx: Context<ProposeTermination>,
+        termination_date: i64,
+        refund_amount: u64,
+    ) -> Result<()> {
+        instructions::propose_termination(ctx, termination_date, refund_amount)
+    }
+
+    pub fn confirm_termination(ctx: Context<ConfirmTermination>) -> Result<()> {
+        instructions::confirm_termination(ctx)
+    }
+
+    pub fn cancel_termination(ctx: Context<CancelTermination>) -> Result<()> {
+        instructions::cancel_termination(ctx)
+    }
+
+    pub fn offer_renewal(ctx: Context<OfferRenewal>, new_end_date: i64, new_rent: u64) -> Result<()> {
+        instructions::offer_renewal(ctx, new_end_date, new_rent)
+    }
+
+    pub fn accept_renewal(ctx: Context<AcceptRenewal>) -> Result<()> {
+        instructions::accept_renewal(ctx)
     }
 
     pub fn initiate_release(
@@ -113,4 +293,13 @@ pub mod zuvi {
     ) -> Result<()> {
         instructions::resolve_dispute(ctx, landlord_amount, tenant_amount)
     }
+
+    #[cfg(feature = "cu-bench")]
+    pub fn benchmark_time_utils(
+        ctx: Context<BenchmarkTimeUtils>,
+        lease_start: i64,
+        current_time: i64,
+    ) -> Result<()> {
+        instructions::benchmark_time_utils(ctx, lease_start, current_time)
+    }
 }
\ No newline at end of file
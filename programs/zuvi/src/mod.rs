@@ -1,5 +1,6 @@
 pub mod constants;
 pub mod errors;
+pub mod escrow_utils;
 pub mod events;
 pub mod instructions;
 pub mod state;
@@ -1,8 +1,19 @@
 use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, time_utils::TimeUtils};
 
 /// 系統配置帳戶
 #[account]
 pub struct Config {
+    /// 管理員公鑰
+    pub admin: Pubkey,
+    /// 待接受的新管理員（兩階段轉移）
+    pub pending_admin: Option<Pubkey>,
+    /// 緊急暫停守護者公鑰
+    pub guardian: Pubkey,
+    /// 暫停旗標位元遮罩 (PAUSE_*)
+    pub paused: u64,
+    /// 全域暫停時是否保持押金釋放路徑開放
+    pub allow_exit_when_paused: bool,
     /// API 簽名者公鑰
     pub api_signer: Pubkey,
     /// 仲裁者公鑰
@@ -13,10 +24,48 @@ pub struct Config {
     pub usdc_mint: Pubkey,
     /// 費率 (basis points, 100 = 1%)
     pub fee_rate: u16,
+    /// 排程中的新費率
+    pub pending_fee_rate: Option<u16>,
+    /// 新費率生效時間 (Unix timestamp)
+    pub fee_rate_effective_at: i64,
+    /// 欠租達此月數時房東可終止租約
+    pub arrears_termination_months: u8,
     /// 是否已初始化
     pub initialized: bool,
 }
 
+impl Config {
+    /// 檢查指定指令是否被暫停
+    pub fn is_paused(&self, flag: u64) -> bool {
+        if self.paused & flag != 0 {
+            return true;
+        }
+        if self.paused & PAUSE_ALL != 0 {
+            return !(self.allow_exit_when_paused && flag & PAUSE_EXIT_PATHS == flag);
+        }
+        false
+    }
+
+    /// 取得指定時間生效中的費率（排程費率到期後即生效）
+    pub fn effective_fee_rate(&self, now: i64) -> u16 {
+        match self.pending_fee_rate {
+            Some(rate) if now >= self.fee_rate_effective_at => rate,
+            _ => self.fee_rate,
+        }
+    }
+
+    /// 將已到期的排程費率寫入 fee_rate
+    pub fn apply_pending_fee_rate(&mut self, now: i64) {
+        if let Some(rate) = self.pending_fee_rate {
+            if now >= self.fee_rate_effective_at {
+                self.fee_rate = rate;
+                self.pending_fee_rate = None;
+                self.fee_rate_effective_at = 0;
+            }
+        }
+    }
+}
+
 /// 房源列表帳戶
 #[account]
 pub struct Listing {
@@ -44,6 +93,33 @@ pub struct Listing {
     pub has_approved_application: bool,
     /// 創建時間戳
     pub created_at: i64,
+    /// 受託管理人
+    pub manager: Option<Pubkey>,
+    /// 管理人權限位元遮罩 (MANAGER_PERMISSION_*)
+    pub manager_permissions: u8,
+    /// 現任房東的產權憑證（過戶後更新，property_attest 仍為 PDA 種子）
+    pub owner_attest: Pubkey,
+    /// 目前生效中的租約
+    pub current_lease: Option<Pubkey>,
+}
+
+impl Listing {
+    /// 檢查簽署者是否為房東，或具備指定權限的管理人
+    pub fn is_authorized(&self, signer: &Pubkey, permission: u8) -> bool {
+        *signer == self.owner
+            || (self.manager == Some(*signer) && self.manager_permissions & permission != 0)
+    }
+
+    /// 租約結束後重新開放房源（房源已由新租約承租時不重設）
+    pub fn release_lease(&mut self, lease: &Pubkey) {
+        if self.current_lease != Some(*lease) {
+            return;
+        }
+        self.status = LISTING_STATUS_AVAILABLE;
+        self.current_tenant = None;
+        self.current_lease = None;
+        self.has_active_lease = false;
+    }
 }
 
 /// 租賃申請帳戶
@@ -79,12 +155,14 @@ pub struct Lease {
     pub rent: u64,
     /// 押金金額 (USDC lamports)
     pub deposit: u64,
+    /// 簽約時鎖定的平台費率 (basis points)
+    pub fee_rate: u16,
     /// 開始日期 (Unix timestamp)
     pub start_date: i64,
     /// 結束日期 (Unix timestamp)
     pub end_date: i64,
     
-    /// 每月繳費日 (1-28)
+    /// 繳費日：月/季/年繳為每月幾號 (1-28)，週繳為星期幾 (1-7)
     pub payment_day: u8,
     /// 已付月數
     pub paid_months: u32,
@@ -99,6 +177,304 @@ pub struct Lease {
     pub landlord_signed: bool,
     /// 承租人是否已簽署
     pub tenant_signed: bool,
+    
+    /// 承租人是否已申請提前終止
+    pub early_termination_requested: bool,
+    /// 提前終止生效日 (Unix timestamp, 0 = 無)
+    pub termination_date: i64,
+    /// 提前終止違約金 (USDC lamports)
+    pub termination_penalty: u64,
+    /// 合意終止提議人
+    pub termination_proposer: Option<Pubkey>,
+    /// 合意終止時自押金退還承租人的租金 (USDC lamports)
+    pub termination_refund: u64,
+    
+    /// 續約提議的新結束日期 (Unix timestamp, 0 = 無)
+    pub renewal_end_date: i64,
+    /// 續約提議的新月租金 (USDC lamports)
+    pub renewal_rent: u64,
+    
+    /// 每年調漲幅度 (basis points)
+    pub escalation_bps: u16,
+    /// 累計調漲上限 (basis points)
+    pub escalation_cap_bps: u16,
+    
+    /// 繳租寬限天數
+    pub grace_days: u8,
+    /// 滯納金類型: 0=無, 1=固定金額, 2=每日 basis points
+    pub late_fee_type: u8,
+    /// 滯納金數值（固定金額或每日 basis points）
+    pub late_fee_value: u64,
+    
+    /// 預付未到期租金折扣 (basis points)
+    pub prepay_discount_bps: u16,
+    
+    /// 繳費週期: 0=月繳, 1=週繳, 2=季繳, 3=年繳
+    pub payment_frequency: u8,
+    /// 計算到期日所用的當地時區 (相對 UTC 的分鐘數)
+    pub utc_offset_minutes: i16,
+    
+    /// 承租人核准租約 PDA 自動扣款的額度 (USDC lamports, 0 = 未啟用)
+    pub auto_debit_cap: u64,
+    
+    /// 是否僅允許承租人與指定代繳人支付租金
+    pub payer_restricted: bool,
+    /// 承租人指定的代繳人（雇主、租金補貼單位等）
+    pub approved_payers: Vec<Pubkey>,
+    
+    /// 共同承租人（主承租人分攤剩餘比例）
+    pub co_tenants: Vec<CoTenant>,
+    /// 本期已繳納分攤租金的承租人位元遮罩（bit 0 = 主承租人）
+    pub contribution_mask: u8,
+    /// 本期已收到的房東分攤款 (USDC lamports)
+    pub period_landlord_funded: u64,
+    /// 本期已收到的平台費分攤款 (USDC lamports)
+    pub period_fee_funded: u64,
+    /// 首筆分攤款繳納時鎖定的本期租金明細，各承租人依此計算分攤金額
+    pub period_charge: Option<RentCharge>,
+    
+    /// 保證人
+    pub guarantor: Option<Pubkey>,
+    /// 保證人是否已簽署並核准代償額度
+    pub guarantor_signed: bool,
+    
+    /// 建立租約時的承租人（租約 PDA 種子，承租人更換錢包後不變）
+    pub original_tenant: Pubkey,
+    
+    /// 簽署前條款的修訂次數，承租人簽署時須與其審閱的版本相符
+    pub terms_revision: u32,
+}
+
+/// 共同承租人
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct CoTenant {
+    /// 共同承租人公鑰
+    pub tenant: Pubkey,
+    /// 租金分攤比例 (basis points)
+    pub share_bps: u16,
+    /// 是否已簽署
+    pub signed: bool,
+}
+
+impl Lease {
+    /// 房東於承租人簽署前修改條款：簽署後條款即固定，修改後共同承租人與保證人須重新簽署
+    pub fn amend_terms(&mut self) -> Result<()> {
+        require!(
+            !self.tenant_signed,
+            ZuviError::AlreadySigned
+        );
+        
+        self.terms_revision = self.terms_revision.checked_add(1).unwrap();
+        for co_tenant in self.co_tenants.iter_mut() {
+            co_tenant.signed = false;
+        }
+        self.guarantor_signed = false;
+        Ok(())
+    }
+
+    /// 檢查是否可代承租人支付租金
+    pub fn can_pay_rent(&self, payer: &Pubkey) -> bool {
+        *payer == self.tenant
+            || !self.payer_restricted
+            || self.approved_payers.contains(payer)
+            || self.co_tenants.iter().any(|co_tenant| co_tenant.tenant == *payer)
+    }
+
+    /// 取得承租人的分攤位元與比例，主承租人分攤共同承租人以外的剩餘比例
+    pub fn rent_share(&self, tenant: &Pubkey) -> Option<(u8, u16)> {
+        if *tenant == self.tenant {
+            let co_tenant_bps: u16 = self.co_tenants.iter().map(|co_tenant| co_tenant.share_bps).sum();
+            return Some((0, 10000 - co_tenant_bps));
+        }
+        self.co_tenants
+            .iter()
+            .position(|co_tenant| co_tenant.tenant == *tenant)
+            .map(|index| (index as u8 + 1, self.co_tenants[index].share_bps))
+    }
+
+    /// 所有承租人皆已繳納分攤租金時的位元遮罩
+    pub fn full_contribution_mask(&self) -> u8 {
+        ((1u16 << (self.co_tenants.len() + 1)) - 1) as u8
+    }
+
+    /// 計算單一承租人應繳的房東款與平台費，最後一位繳納者補足分攤的尾差
+    pub fn share_amounts(&self, charge: &RentCharge, share_bps: u16, fully_funded: bool) -> Result<(u64, u64)> {
+        if fully_funded {
+            return Ok((
+                charge.landlord_amount()
+                    .checked_sub(self.period_landlord_funded)
+                    .ok_or(ZuviError::AmountMismatch)?,
+                charge.platform_fee
+                    .checked_sub(self.period_fee_funded)
+                    .ok_or(ZuviError::AmountMismatch)?,
+            ));
+        }
+        Ok((
+            charge.landlord_amount()
+                .checked_mul(share_bps as u64).unwrap()
+                .checked_div(10000).unwrap(),
+            charge.platform_fee
+                .checked_mul(share_bps as u64).unwrap()
+                .checked_div(10000).unwrap(),
+        ))
+    }
+
+    /// 每年的繳費期數（用於年度調漲與租金換算）
+    pub fn periods_per_year(&self) -> u32 {
+        match self.payment_frequency {
+            PAYMENT_FREQUENCY_WEEKLY => 52,
+            PAYMENT_FREQUENCY_QUARTERLY => 4,
+            PAYMENT_FREQUENCY_YEARLY => 1,
+            _ => 12,
+        }
+    }
+
+    /// 依繳費週期取得寬限期上限
+    pub fn max_grace_days(&self) -> u8 {
+        if self.payment_frequency == PAYMENT_FREQUENCY_WEEKLY {
+            MAX_WEEKLY_GRACE_DAYS
+        } else {
+            MAX_GRACE_DAYS
+        }
+    }
+
+    /// 計算第 period_index 期（0 起算）的到期日
+    pub fn payment_due(&self, period_index: u32) -> Result<i64> {
+        TimeUtils::calculate_next_payment_due(
+            self.start_date,
+            self.payment_day,
+            self.payment_frequency,
+            self.utc_offset_minutes,
+            period_index,
+        )
+    }
+
+    /// 計算截至指定時間應已支付的總期數（含簽約時支付的首期）
+    pub fn payments_due(&self, current_time: i64) -> Result<u32> {
+        TimeUtils::calculate_payments_due(
+            current_time,
+            self.start_date,
+            self.payment_day,
+            self.payment_frequency,
+            self.utc_offset_minutes,
+        )
+    }
+
+    /// 計算租期至指定結束日應支付的總期數
+    pub fn total_payments_until(&self, lease_end: i64) -> Result<u32> {
+        TimeUtils::calculate_total_payments(
+            self.start_date,
+            lease_end,
+            self.payment_day,
+            self.payment_frequency,
+            self.utc_offset_minutes,
+        )
+    }
+
+    /// 將月租金換算為單期租金（週繳以 12/52 個月計）
+    pub fn base_period_rent(&self) -> u64 {
+        self.rent
+            .checked_mul(12).unwrap()
+            .checked_div(self.periods_per_year() as u64).unwrap()
+    }
+
+    /// 計算第 month_index 期（0 起算）適用的租金（含年度調漲）
+    pub fn rent_for_month(&self, month_index: u32) -> u64 {
+        let years = (month_index / self.periods_per_year()) as u64;
+        let increase_bps = (self.escalation_bps as u64 * years).min(self.escalation_cap_bps as u64);
+        self.base_period_rent()
+            .checked_mul(10000 + increase_bps).unwrap()
+            .checked_div(10000).unwrap()
+    }
+
+    /// 計算第 period_index 期（0 起算）實際應收租金（含年度調漲與首末期按日計算）
+    pub fn period_rent(&self, period_index: u32) -> Result<u64> {
+        self.period_rent_until(period_index, self.end_date)
+    }
+
+    /// 以指定的租約結束日計算第 period_index 期的租金（續約時補收原末期差額用）
+    pub fn period_rent_until(&self, period_index: u32, lease_end: i64) -> Result<u64> {
+        TimeUtils::calculate_prorated_rent(
+            self.rent_for_month(period_index),
+            self.start_date,
+            lease_end,
+            self.payment_day,
+            self.payment_frequency,
+            self.utc_offset_minutes,
+            period_index,
+        )
+    }
+
+    /// 計算逾期滯納金（超過寬限期才收取，上限為當期租金的 LATE_FEE_CAP_BPS）
+    pub fn late_fee(&self, rent_amount: u64, due_date: i64, paid_at: i64) -> u64 {
+        let days_late = (paid_at - due_date) / SECONDS_PER_DAY;
+        let days_over_grace = days_late - self.grace_days as i64;
+        if days_over_grace <= 0 {
+            return 0;
+        }
+        
+        let fee = match self.late_fee_type {
+            LATE_FEE_TYPE_FLAT => self.late_fee_value,
+            LATE_FEE_TYPE_DAILY_BPS => rent_amount
+                .checked_mul(self.late_fee_value).unwrap()
+                .checked_mul(days_over_grace as u64).unwrap()
+                .checked_div(10000).unwrap(),
+            _ => 0,
+        };
+        let cap = rent_amount
+            .checked_mul(LATE_FEE_CAP_BPS).unwrap()
+            .checked_div(10000).unwrap();
+        fee.min(cap)
+    }
+
+    /// 計算第 month_index 期（0 起算）於 paid_at 支付時的租金明細
+    /// 尚未到期的期數視為預付，套用預付折扣後再計算平台費
+    pub fn rent_charge(&self, month_index: u32, paid_at: i64) -> Result<RentCharge> {
+        let due_date = self.payment_due(month_index)?;
+        let full_rent = self.period_rent(month_index)?;
+        let discount = if paid_at < due_date {
+            full_rent
+                .checked_mul(self.prepay_discount_bps as u64).unwrap()
+                .checked_div(10000).unwrap()
+        } else {
+            0
+        };
+        let rent_amount = full_rent - discount;
+        let platform_fee = rent_amount
+            .checked_mul(self.fee_rate as u64).unwrap()
+            .checked_div(10000).unwrap();
+        let late_fee = self.late_fee(rent_amount, due_date, paid_at);
+        
+        Ok(RentCharge {
+            month: month_index + 1,
+            rent_amount,
+            discount,
+            platform_fee,
+            late_fee,
+        })
+    }
+}
+
+/// 單期租金明細
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RentCharge {
+    /// 期數（1 起算）
+    pub month: u32,
+    /// 當期租金（已扣除預付折扣）
+    pub rent_amount: u64,
+    /// 預付折扣
+    pub discount: u64,
+    /// 平台費
+    pub platform_fee: u64,
+    /// 滯納金（全額歸房東）
+    pub late_fee: u64,
+}
+
+impl RentCharge {
+    /// 房東實收金額（租金扣除平台費，加上滯納金）
+    pub fn landlord_amount(&self) -> u64 {
+        self.rent_amount - self.platform_fee + self.late_fee
+    }
 }
 
 /// 押金託管帳戶
@@ -108,7 +484,7 @@ pub struct Escrow {
     pub lease: Pubkey,
     /// 押金總額 (USDC lamports)
     pub amount: u64,
-    /// 狀態: 0=持有中, 1=釋放中, 2=已釋放
+    /// 狀態: 0=持有中, 1=釋放中, 2=已釋放, 3=凍結待結算
     pub status: u8,
     /// 分配給房東的金額
     pub release_to_landlord: u64,
@@ -135,4 +511,231 @@ pub struct Dispute {
     pub status: u8,
     /// 創建時間戳
     pub created_at: i64,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RENT: u64 = 30_000_000;
+
+    fn config() -> Config {
+        Config {
+            admin: Pubkey::new_unique(),
+            pending_admin: None,
+            guardian: Pubkey::new_unique(),
+            paused: 0,
+            allow_exit_when_paused: false,
+            api_signer: Pubkey::new_unique(),
+            arbitrator: Pubkey::new_unique(),
+            fee_receiver: Pubkey::new_unique(),
+            usdc_mint: Pubkey::new_unique(),
+            fee_rate: 100,
+            pending_fee_rate: None,
+            fee_rate_effective_at: 0,
+            arrears_termination_months: 2,
+            initialized: true,
+        }
+    }
+
+    /// 2025-01-01 起、每月 1 日繳費的兩年租約
+    fn lease() -> Lease {
+        Lease {
+            listing: Pubkey::new_unique(),
+            landlord: Pubkey::new_unique(),
+            tenant: Pubkey::new_unique(),
+            tenant_attest: Pubkey::new_unique(),
+            rent: RENT,
+            deposit: RENT * 2,
+            fee_rate: 100,
+            start_date: TimeUtils::date_to_timestamp(2025, 1, 1).unwrap(),
+            end_date: TimeUtils::date_to_timestamp(2027, 1, 1).unwrap(),
+            payment_day: 1,
+            paid_months: 0,
+            last_payment: 0,
+            contract_uri: [0; 64],
+            status: LEASE_STATUS_ACTIVE,
+            landlord_signed: true,
+            tenant_signed: true,
+            early_termination_requested: false,
+            termination_date: 0,
+            termination_penalty: 0,
+            termination_proposer: None,
+            termination_refund: 0,
+            renewal_end_date: 0,
+            renewal_rent: 0,
+            escalation_bps: 0,
+            escalation_cap_bps: 0,
+            grace_days: 0,
+            late_fee_type: LATE_FEE_TYPE_NONE,
+            late_fee_value: 0,
+            prepay_discount_bps: 0,
+            payment_frequency: PAYMENT_FREQUENCY_MONTHLY,
+            utc_offset_minutes: 0,
+            auto_debit_cap: 0,
+            payer_restricted: false,
+            approved_payers: vec![],
+            co_tenants: vec![],
+            contribution_mask: 0,
+            period_landlord_funded: 0,
+            period_fee_funded: 0,
+            period_charge: None,
+            guarantor: None,
+            guarantor_signed: false,
+            original_tenant: Pubkey::new_unique(),
+            terms_revision: 0,
+        }
+    }
+
+    #[test]
+    fn late_fee_starts_after_grace_period() {
+        let mut lease = lease();
+        lease.grace_days = 5;
+        lease.late_fee_type = LATE_FEE_TYPE_FLAT;
+        lease.late_fee_value = 500_000;
+        let due = lease.payment_due(1).unwrap();
+
+        // 寬限期最後一天仍不收取，之後才收取
+        assert_eq!(lease.late_fee(RENT, due, due + 5 * SECONDS_PER_DAY), 0);
+        assert_eq!(lease.late_fee(RENT, due, due + 6 * SECONDS_PER_DAY - 1), 0);
+        assert_eq!(lease.late_fee(RENT, due, due + 6 * SECONDS_PER_DAY), 500_000);
+        assert_eq!(lease.late_fee(RENT, due, due - SECONDS_PER_DAY), 0);
+    }
+
+    #[test]
+    fn late_fee_is_capped() {
+        let mut lease = lease();
+        lease.grace_days = 3;
+        lease.late_fee_type = LATE_FEE_TYPE_DAILY_BPS;
+        lease.late_fee_value = 100;
+        let due = lease.payment_due(1).unwrap();
+        let cap = RENT * LATE_FEE_CAP_BPS / 10000;
+
+        assert_eq!(lease.late_fee(RENT, due, due + 4 * SECONDS_PER_DAY), RENT / 100);
+        assert_eq!(lease.late_fee(RENT, due, due + 13 * SECONDS_PER_DAY), cap);
+        assert_eq!(lease.late_fee(RENT, due, due + 60 * SECONDS_PER_DAY), cap);
+
+        // 固定金額同樣受上限限制
+        lease.late_fee_type = LATE_FEE_TYPE_FLAT;
+        lease.late_fee_value = cap + 1;
+        assert_eq!(lease.late_fee(RENT, due, due + 4 * SECONDS_PER_DAY), cap);
+    }
+
+    #[test]
+    fn escalation_applies_at_year_boundaries() {
+        let mut lease = lease();
+        lease.escalation_bps = 300;
+        lease.escalation_cap_bps = 500;
+
+        assert_eq!(lease.rent_for_month(0), RENT);
+        assert_eq!(lease.rent_for_month(11), RENT);
+        assert_eq!(lease.rent_for_month(12), RENT * 10300 / 10000);
+        assert_eq!(lease.rent_for_month(23), RENT * 10300 / 10000);
+        // 第三年累計 600 bps，以上限 500 bps 計
+        assert_eq!(lease.rent_for_month(24), RENT * 10500 / 10000);
+        assert_eq!(lease.rent_for_month(60), RENT * 10500 / 10000);
+
+        // 季繳以 4 期為一年
+        lease.payment_frequency = PAYMENT_FREQUENCY_QUARTERLY;
+        assert_eq!(lease.rent_for_month(3), RENT * 3);
+        assert_eq!(lease.rent_for_month(4), RENT * 3 * 10300 / 10000);
+    }
+
+    #[test]
+    fn prepay_discount_applies_before_platform_fee() {
+        let mut lease = lease();
+        lease.prepay_discount_bps = 200;
+        let due = lease.payment_due(1).unwrap();
+
+        // 到期前支付視為預付，平台費以折扣後租金計算
+        let prepaid = lease.rent_charge(1, due - 1).unwrap();
+        assert_eq!(prepaid.month, 2);
+        assert_eq!(prepaid.discount, RENT * 200 / 10000);
+        assert_eq!(prepaid.rent_amount, RENT - prepaid.discount);
+        assert_eq!(prepaid.platform_fee, prepaid.rent_amount * 100 / 10000);
+        assert_eq!(prepaid.landlord_amount(), prepaid.rent_amount - prepaid.platform_fee);
+
+        // 到期當日起不再折扣
+        let on_time = lease.rent_charge(1, due).unwrap();
+        assert_eq!(on_time.discount, 0);
+        assert_eq!(on_time.rent_amount, RENT);
+        assert_eq!(on_time.platform_fee, RENT * 100 / 10000);
+    }
+
+    #[test]
+    fn last_co_tenant_covers_rounding_remainder() {
+        let mut lease = lease();
+        lease.co_tenants = vec![
+            CoTenant { tenant: Pubkey::new_unique(), share_bps: 3333, signed: true },
+            CoTenant { tenant: Pubkey::new_unique(), share_bps: 3333, signed: true },
+        ];
+        let main_tenant = lease.tenant;
+        let co_tenant = lease.co_tenants[0].tenant;
+        assert_eq!(lease.rent_share(&main_tenant), Some((0, 3334)));
+        assert_eq!(lease.rent_share(&co_tenant), Some((1, 3333)));
+        assert_eq!(lease.full_contribution_mask(), 0b111);
+
+        let charge = RentCharge {
+            month: 1,
+            rent_amount: 10_000_001,
+            discount: 0,
+            platform_fee: 100_001,
+            late_fee: 7,
+        };
+        let mut landlord_total = 0;
+        let mut fee_total = 0;
+        for (index, share_bps) in [3333u16, 3333, 3334].into_iter().enumerate() {
+            let (landlord_amount, platform_fee) = lease.share_amounts(&charge, share_bps, index == 2).unwrap();
+            lease.period_landlord_funded += landlord_amount;
+            lease.period_fee_funded += platform_fee;
+            landlord_total += landlord_amount;
+            fee_total += platform_fee;
+        }
+        assert_eq!(landlord_total, charge.landlord_amount());
+        assert_eq!(fee_total, charge.platform_fee);
+
+        // 已繳金額超過明細時拒絕
+        lease.period_fee_funded = charge.platform_fee + 1;
+        assert!(lease.share_amounts(&charge, 3334, true).is_err());
+    }
+
+    #[test]
+    fn is_paused_respects_exit_paths() {
+        let mut config = config();
+        assert!(!config.is_paused(PAUSE_PAY_RENT));
+
+        config.paused = PAUSE_PAY_RENT;
+        assert!(config.is_paused(PAUSE_PAY_RENT));
+        assert!(!config.is_paused(PAUSE_CONFIRM_RELEASE));
+
+        config.paused = PAUSE_ALL;
+        assert!(config.is_paused(PAUSE_PAY_RENT));
+        assert!(config.is_paused(PAUSE_CONFIRM_RELEASE));
+
+        // 全域暫停時可保留押金釋放路徑，但個別暫停的旗標仍生效
+        config.allow_exit_when_paused = true;
+        assert!(config.is_paused(PAUSE_PAY_RENT));
+        assert!(!config.is_paused(PAUSE_CONFIRM_RELEASE));
+        config.paused = PAUSE_ALL | PAUSE_CONFIRM_RELEASE;
+        assert!(config.is_paused(PAUSE_CONFIRM_RELEASE));
+    }
+
+    #[test]
+    fn fee_rate_changes_after_timelock() {
+        let mut config = config();
+        config.pending_fee_rate = Some(250);
+        config.fee_rate_effective_at = 1_000;
+
+        assert_eq!(config.effective_fee_rate(999), 100);
+        assert_eq!(config.effective_fee_rate(1_000), 250);
+
+        config.apply_pending_fee_rate(999);
+        assert_eq!(config.fee_rate, 100);
+        assert_eq!(config.pending_fee_rate, Some(250));
+
+        config.apply_pending_fee_rate(1_000);
+        assert_eq!(config.fee_rate, 250);
+        assert_eq!(config.pending_fee_rate, None);
+        assert_eq!(config.effective_fee_rate(0), 250);
+    }
+}
@@ -1,3 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::ZuviError};
+
 pub struct TimeUtils;
 
 impl TimeUtils {
@@ -7,84 +10,146 @@ impl TimeUtils {
     }
 
     /// 取得指定月份的天數
-    pub fn days_in_month(year: i32, month: u32) -> u32 {
+    pub fn days_in_month(year: i32, month: u32) -> Result<u32> {
         match month {
-            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-            4 | 6 | 9 | 11 => 30,
-            2 => if Self::is_leap_year(year) { 29 } else { 28 },
-            _ => 0,
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => Ok(31),
+            4 | 6 | 9 | 11 => Ok(30),
+            2 => Ok(if Self::is_leap_year(year) { 29 } else { 28 }),
+            _ => Err(ZuviError::InvalidMonth.into()),
         }
     }
 
-    /// 從Unix時間戳轉換為年月日
-    pub fn timestamp_to_date(timestamp: i64) -> (i32, u32, u32) {
-        let days_since_epoch = timestamp / 86400;
-        let mut year = 1970;
-        let mut remaining_days = days_since_epoch;
+    /// 檢查時間戳是否在支援範圍內（1970-01-01 至 9999-12-31）
+    pub fn validate_timestamp(timestamp: i64) -> Result<()> {
+        require!(
+            (0..=MAX_SUPPORTED_TIMESTAMP).contains(&timestamp),
+            ZuviError::DateOutOfRange
+        );
+        Ok(())
+    }
 
-        // 計算年份
-        loop {
-            let days_in_year = if Self::is_leap_year(year) { 366 } else { 365 };
-            if remaining_days < days_in_year {
-                break;
-            }
-            remaining_days -= days_in_year;
-            year += 1;
-        }
+    /// 從Unix時間戳轉換為年月日（Howard Hinnant civil_from_days，常數時間）
+    pub fn timestamp_to_date(timestamp: i64) -> Result<(i32, u32, u32)> {
+        Self::validate_timestamp(timestamp)?;
 
-        // 計算月份和日期
-        let mut month = 1;
-        while month <= 12 {
-            let days_in_current_month = Self::days_in_month(year, month);
-            if remaining_days < days_in_current_month as i64 {
-                break;
-            }
-            remaining_days -= days_in_current_month as i64;
-            month += 1;
-        }
+        // 以 0000-03-01 為基準，使閏日落在每個 400 年週期的最後
+        let z = timestamp.div_euclid(86400) + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z.rem_euclid(146097); // [0, 146096]
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365; // [0, 399]
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+        let mp = (5 * doy + 2) / 153; // [0, 11]，由三月起算
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+        Ok((year as i32, month, day))
+    }
+
+    /// 從年月日轉換為Unix時間戳（UTC午夜，Howard Hinnant days_from_civil，常數時間）
+    pub fn date_to_timestamp(year: i32, month: u32, day: u32) -> Result<i64> {
+        require!(
+            (MIN_SUPPORTED_YEAR..=MAX_SUPPORTED_YEAR).contains(&year),
+            ZuviError::DateOutOfRange
+        );
+        let max_day = Self::days_in_month(year, month)?;
+        require!(
+            (1..=max_day).contains(&day),
+            ZuviError::InvalidDayOfMonth
+        );
 
-        let day = (remaining_days + 1) as u32;
-        (year, month, day)
+        let y = year as i64 - if month <= 2 { 1 } else { 0 };
+        let era = y.div_euclid(400);
+        let yoe = y.rem_euclid(400); // [0, 399]
+        let mp = (month as i64 + 9) % 12; // [0, 11]，由三月起算
+        let doy = (153 * mp + 2) / 5 + day as i64 - 1; // [0, 365]
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+        let days = era * 146097 + doe - 719468;
+
+        Ok(days * 86400) // 轉換為秒
     }
 
-    /// 從年月日轉換為Unix時間戳（UTC午夜）
-    pub fn date_to_timestamp(year: i32, month: u32, day: u32) -> i64 {
-        let mut days = 0i64;
+    /// 從Unix時間戳轉換為當地年月日
+    pub fn timestamp_to_local_date(timestamp: i64, utc_offset_minutes: i16) -> Result<(i32, u32, u32)> {
+        Self::timestamp_to_date(timestamp.saturating_add(utc_offset_minutes as i64 * 60))
+    }
 
-        // 計算從1970年到指定年份的天數
-        for y in 1970..year {
-            days += if Self::is_leap_year(y) { 366 } else { 365 };
-        }
+    /// 從當地年月日轉換為Unix時間戳（當地午夜）
+    pub fn local_date_to_timestamp(year: i32, month: u32, day: u32, utc_offset_minutes: i16) -> Result<i64> {
+        Ok(Self::date_to_timestamp(year, month, day)? - utc_offset_minutes as i64 * 60)
+    }
 
-        // 加上當年到指定月份的天數
-        for m in 1..month {
-            days += Self::days_in_month(year, m) as i64;
-        }
+    /// 取得從指定年月起算 offset 個月後的年月
+    fn add_months(year: i32, month: u32, offset: i32) -> (i32, u32) {
+        let total_months = year * 12 + (month as i32 - 1) + offset;
+        (total_months.div_euclid(12), (total_months.rem_euclid(12) + 1) as u32)
+    }
 
-        // 加上當月的天數（減1因為從第1天開始）
-        days += (day - 1) as i64;
+    /// 取得指定時間戳在當地為星期幾（1=週一 ... 7=週日）
+    pub fn weekday(timestamp: i64, utc_offset_minutes: i16) -> u8 {
+        // 1970-01-01 為週四
+        ((Self::local_day_number(timestamp, utc_offset_minutes) + 3).rem_euclid(7) + 1) as u8
+    }
 
-        days * 86400 // 轉換為秒
+    /// 取得指定時間戳在當地自 1970-01-01 起算的日數
+    fn local_day_number(timestamp: i64, utc_offset_minutes: i16) -> i64 {
+        (timestamp + utc_offset_minutes as i64 * 60).div_euclid(86400)
     }
 
-    /// 計算下次租金到期日
-    pub fn calculate_next_payment_due(
+    /// 取得各繳費週期的月數（週繳不適用）
+    fn months_per_period(frequency: u8) -> i32 {
+        match frequency {
+            PAYMENT_FREQUENCY_QUARTERLY => 3,
+            PAYMENT_FREQUENCY_YEARLY => 12,
+            _ => 1,
+        }
+    }
+
+    /// 計算起租後第 period 個繳費日（0 = 起租日之後的第一個繳費日，-1 = 其前一期）
+    /// 週繳時 payment_day 為星期幾（1=週一 ... 7=週日），其餘為每月幾號
+    /// 到期時間為當地午夜
+    fn payment_date_after_start(
         lease_start: i64,
         payment_day: u8,
-        paid_months: u32,
-    ) -> i64 {
-        let (start_year, start_month, _) = Self::timestamp_to_date(lease_start);
-        
-        // 計算目標月份
-        let total_months = (start_month - 1) + paid_months + 1; // +1 for next payment
-        let target_year = start_year + (total_months / 12) as i32;
-        let target_month = (total_months % 12) + 1;
+        frequency: u8,
+        utc_offset_minutes: i16,
+        period: i32,
+    ) -> Result<i64> {
+        Self::validate_timestamp(lease_start)?;
+
+        if frequency == PAYMENT_FREQUENCY_WEEKLY {
+            let start_day = Self::local_day_number(lease_start, utc_offset_minutes);
+            let start_weekday = Self::weekday(lease_start, utc_offset_minutes) as i64;
+            // 首個繳費日為起租日之後（不含當天）的第一個指定星期
+            let days_to_first = (payment_day as i64 - start_weekday - 1).rem_euclid(7) + 1;
+            return Ok((start_day + days_to_first + period as i64 * 7) * 86400 - utc_offset_minutes as i64 * 60);
+        }
+
+        let (start_year, start_month, start_day) = Self::timestamp_to_local_date(lease_start, utc_offset_minutes)?;
+
+        // 起租日早於當月繳費日時，首個繳費日在當月，否則在次月
+        let first_offset = if start_day < payment_day as u32 { 0 } else { 1 };
+        let offset = first_offset + period * Self::months_per_period(frequency);
+        let (target_year, target_month) = Self::add_months(start_year, start_month, offset);
 
         // 確保payment_day不超過該月的天數
-        let max_day_in_month = Self::days_in_month(target_year, target_month);
+        let max_day_in_month = Self::days_in_month(target_year, target_month)?;
         let actual_payment_day = (payment_day as u32).min(max_day_in_month);
 
-        Self::date_to_timestamp(target_year, target_month, actual_payment_day)
+        Self::local_date_to_timestamp(target_year, target_month, actual_payment_day, utc_offset_minutes)
+    }
+
+    /// 計算下次租金到期日（首期於簽約時支付，到期日即起租日）
+    pub fn calculate_next_payment_due(
+        lease_start: i64,
+        payment_day: u8,
+        frequency: u8,
+        utc_offset_minutes: i16,
+        paid_months: u32,
+    ) -> Result<i64> {
+        if paid_months == 0 {
+            return Ok(lease_start);
+        }
+        Self::payment_date_after_start(lease_start, payment_day, frequency, utc_offset_minutes, paid_months as i32 - 1)
     }
 
     /// 檢查租金是否到期
@@ -92,28 +157,391 @@ impl TimeUtils {
         current_time: i64,
         lease_start: i64,
         payment_day: u8,
+        frequency: u8,
+        utc_offset_minutes: i16,
         paid_months: u32,
-    ) -> bool {
-        let next_due_date = Self::calculate_next_payment_due(lease_start, payment_day, paid_months);
-        current_time >= next_due_date
+    ) -> Result<bool> {
+        let next_due_date = Self::calculate_next_payment_due(lease_start, payment_day, frequency, utc_offset_minutes, paid_months)?;
+        Ok(current_time >= next_due_date)
     }
 
-    /// 計算已經應該支付的月數
+    /// 計算已經到期的繳費日數（不含簽約時支付的首期）
     pub fn calculate_months_due(
         current_time: i64,
         lease_start: i64,
         payment_day: u8,
-    ) -> u32 {
-        let mut months_due = 0;
-        
+        frequency: u8,
+        utc_offset_minutes: i16,
+    ) -> Result<u32> {
+        let first_due = Self::payment_date_after_start(lease_start, payment_day, frequency, utc_offset_minutes, 0)?;
+        if current_time < first_due {
+            return Ok(0);
+        }
+
+        // 先以日數或月份差推算最後一個已到期的繳費日，再校正同月份內尚未到期的情況
+        let last_period = if frequency == PAYMENT_FREQUENCY_WEEKLY {
+            (current_time - first_due).div_euclid(7 * 86400)
+        } else {
+            let (first_year, first_month, _) = Self::timestamp_to_local_date(first_due, utc_offset_minutes)?;
+            let (current_year, current_month, _) = Self::timestamp_to_local_date(current_time, utc_offset_minutes)?;
+            let months_elapsed = (current_year - first_year) as i64 * 12 + current_month as i64 - first_month as i64;
+            let period = months_elapsed.div_euclid(Self::months_per_period(frequency) as i64);
+            let due = Self::payment_date_after_start(lease_start, payment_day, frequency, utc_offset_minutes, period as i32)?;
+            if current_time < due { period - 1 } else { period }
+        };
+
+        Ok((last_period + 1) as u32)
+    }
+
+    /// 計算截至指定時間應已支付的總期數（含簽約時支付的首期）
+    pub fn calculate_payments_due(
+        current_time: i64,
+        lease_start: i64,
+        payment_day: u8,
+        frequency: u8,
+        utc_offset_minutes: i16,
+    ) -> Result<u32> {
+        Ok(Self::calculate_months_due(current_time, lease_start, payment_day, frequency, utc_offset_minutes)? + 1)
+    }
+
+    /// 計算整個租期應支付的總期數（到期日須早於租約結束日）
+    pub fn calculate_total_payments(
+        lease_start: i64,
+        lease_end: i64,
+        payment_day: u8,
+        frequency: u8,
+        utc_offset_minutes: i16,
+    ) -> Result<u32> {
+        Self::calculate_payments_due(lease_end - 1, lease_start, payment_day, frequency, utc_offset_minutes)
+    }
+
+    /// 計算兩個時間戳之間相差的當地日數
+    pub fn days_between(from: i64, to: i64, utc_offset_minutes: i16) -> i64 {
+        Self::local_day_number(to, utc_offset_minutes) - Self::local_day_number(from, utc_offset_minutes)
+    }
+
+    /// 依實際天數計算第 period_index 期（0 起算）的租金
+    /// 首期為起租日至首個繳費日，末期為最後繳費日至租約結束日，其餘為完整一期
+    pub fn calculate_prorated_rent(
+        period_rent: u64,
+        lease_start: i64,
+        lease_end: i64,
+        payment_day: u8,
+        frequency: u8,
+        utc_offset_minutes: i16,
+        period_index: u32,
+    ) -> Result<u64> {
+        let (period_start, full_period_start) = if period_index == 0 {
+            (lease_start, Self::payment_date_after_start(lease_start, payment_day, frequency, utc_offset_minutes, -1)?)
+        } else {
+            let due = Self::calculate_next_payment_due(lease_start, payment_day, frequency, utc_offset_minutes, period_index)?;
+            (due, due)
+        };
+        let full_period_end = Self::calculate_next_payment_due(lease_start, payment_day, frequency, utc_offset_minutes, period_index + 1)?;
+        let period_end = full_period_end.min(lease_end);
+
+        let charged_days = Self::days_between(period_start, period_end, utc_offset_minutes).max(0);
+        let full_days = Self::days_between(full_period_start, full_period_end, utc_offset_minutes);
+        if charged_days >= full_days {
+            return Ok(period_rent);
+        }
+
+        Ok(period_rent
+            .checked_mul(charged_days as u64).unwrap()
+            .checked_div(full_days as u64).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 原本逐年累加的實作，作為常數時間演算法的對照
+    fn reference_timestamp_to_date(timestamp: i64) -> (i32, u32, u32) {
+        let mut year = 1970;
+        let mut remaining_days = timestamp / 86400;
         loop {
-            let next_due = Self::calculate_next_payment_due(lease_start, payment_day, months_due);
-            if current_time < next_due {
+            let days_in_year = if TimeUtils::is_leap_year(year) { 366 } else { 365 };
+            if remaining_days < days_in_year {
                 break;
             }
+            remaining_days -= days_in_year;
+            year += 1;
+        }
+        let mut month = 1;
+        while month <= 12 {
+            let days_in_current_month = TimeUtils::days_in_month(year, month).unwrap() as i64;
+            if remaining_days < days_in_current_month {
+                break;
+            }
+            remaining_days -= days_in_current_month;
+            month += 1;
+        }
+        (year, month, (remaining_days + 1) as u32)
+    }
+
+    fn reference_date_to_timestamp(year: i32, month: u32, day: u32) -> i64 {
+        let mut days = 0i64;
+        for y in 1970..year {
+            days += if TimeUtils::is_leap_year(y) { 366 } else { 365 };
+        }
+        for m in 1..month {
+            days += TimeUtils::days_in_month(year, m).unwrap() as i64;
+        }
+        days += (day - 1) as i64;
+        days * 86400
+    }
+
+    /// 逐期累加的到期期數，作為 calculate_months_due 的對照
+    fn reference_months_due(
+        current_time: i64,
+        lease_start: i64,
+        payment_day: u8,
+        frequency: u8,
+        utc_offset_minutes: i16,
+    ) -> u32 {
+        let mut months_due = 0;
+        while TimeUtils::calculate_next_payment_due(lease_start, payment_day, frequency, utc_offset_minutes, months_due + 1)
+            .unwrap()
+            <= current_time
+        {
             months_due += 1;
         }
-        
         months_due
     }
-}
\ No newline at end of file
+
+    fn error_code(result: Result<impl std::fmt::Debug>) -> u32 {
+        match result.unwrap_err() {
+            Error::AnchorError(error) => error.error_code_number,
+            error => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    fn zuvi_error_code(error: ZuviError) -> u32 {
+        error as u32 + anchor_lang::error::ERROR_CODE_OFFSET
+    }
+
+    #[test]
+    fn timestamp_to_date_matches_reference() {
+        // 1970-01-01 至 2400-12-31 的每一天，並涵蓋當天不同時刻
+        let last_day = reference_date_to_timestamp(2400, 12, 31) / 86400;
+        for day in 0..=last_day {
+            let expected = reference_timestamp_to_date(day * 86400);
+            assert_eq!(TimeUtils::timestamp_to_date(day * 86400).unwrap(), expected, "day {}", day);
+            assert_eq!(TimeUtils::timestamp_to_date(day * 86400 + 86399).unwrap(), expected, "day {}", day);
+        }
+    }
+
+    #[test]
+    fn date_to_timestamp_matches_reference() {
+        for year in 1970..=2400 {
+            for month in 1..=12 {
+                for day in 1..=TimeUtils::days_in_month(year, month).unwrap() {
+                    assert_eq!(
+                        TimeUtils::date_to_timestamp(year, month, day).unwrap(),
+                        reference_date_to_timestamp(year, month, day),
+                        "{}-{}-{}",
+                        year,
+                        month,
+                        day
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn supported_range_round_trips() {
+        assert_eq!(TimeUtils::timestamp_to_date(0).unwrap(), (1970, 1, 1));
+        assert_eq!(TimeUtils::timestamp_to_date(MAX_SUPPORTED_TIMESTAMP).unwrap(), (9999, 12, 31));
+        assert_eq!(
+            TimeUtils::date_to_timestamp(9999, 12, 31).unwrap() + 86399,
+            MAX_SUPPORTED_TIMESTAMP
+        );
+
+        // 2400 年之後每 97 天抽樣一次
+        let first_day = TimeUtils::date_to_timestamp(2401, 1, 1).unwrap() / 86400;
+        for day in (first_day..=MAX_SUPPORTED_TIMESTAMP / 86400).step_by(97) {
+            let (year, month, date) = TimeUtils::timestamp_to_date(day * 86400).unwrap();
+            assert_eq!(TimeUtils::date_to_timestamp(year, month, date).unwrap(), day * 86400);
+        }
+    }
+
+    #[test]
+    fn malformed_dates_are_rejected() {
+        let out_of_range = zuvi_error_code(ZuviError::DateOutOfRange);
+        assert_eq!(error_code(TimeUtils::timestamp_to_date(-1)), out_of_range);
+        assert_eq!(error_code(TimeUtils::timestamp_to_date(MAX_SUPPORTED_TIMESTAMP + 1)), out_of_range);
+        assert_eq!(error_code(TimeUtils::timestamp_to_date(i64::MIN)), out_of_range);
+        assert_eq!(error_code(TimeUtils::date_to_timestamp(1969, 12, 31)), out_of_range);
+        assert_eq!(error_code(TimeUtils::date_to_timestamp(10000, 1, 1)), out_of_range);
+
+        let invalid_month = zuvi_error_code(ZuviError::InvalidMonth);
+        assert_eq!(error_code(TimeUtils::days_in_month(2024, 0)), invalid_month);
+        assert_eq!(error_code(TimeUtils::days_in_month(2024, 13)), invalid_month);
+        assert_eq!(error_code(TimeUtils::date_to_timestamp(2024, 13, 1)), invalid_month);
+
+        let invalid_day = zuvi_error_code(ZuviError::InvalidDayOfMonth);
+        assert_eq!(error_code(TimeUtils::date_to_timestamp(2024, 1, 0)), invalid_day);
+        assert_eq!(error_code(TimeUtils::date_to_timestamp(2023, 2, 29)), invalid_day);
+        assert_eq!(error_code(TimeUtils::date_to_timestamp(2024, 4, 31)), invalid_day);
+
+        // 當地時區換算後落在支援範圍之外
+        assert_eq!(error_code(TimeUtils::timestamp_to_local_date(0, -60)), out_of_range);
+        assert_eq!(
+            error_code(TimeUtils::calculate_next_payment_due(-86400, 1, PAYMENT_FREQUENCY_WEEKLY, 0, 1)),
+            out_of_range
+        );
+    }
+
+    #[test]
+    fn months_due_matches_reference() {
+        let frequencies = [
+            (PAYMENT_FREQUENCY_MONTHLY, MAX_PAYMENT_DAY),
+            (PAYMENT_FREQUENCY_WEEKLY, MAX_WEEKLY_PAYMENT_DAY),
+            (PAYMENT_FREQUENCY_QUARTERLY, MAX_PAYMENT_DAY),
+            (PAYMENT_FREQUENCY_YEARLY, MAX_PAYMENT_DAY),
+        ];
+        let offsets = [MIN_UTC_OFFSET_MINUTES, -300, 0, DEFAULT_UTC_OFFSET_MINUTES, MAX_UTC_OFFSET_MINUTES];
+        let base = TimeUtils::date_to_timestamp(2024, 1, 1).unwrap();
+
+        for start_step in 0..24 {
+            // 起租時間刻意不對齊午夜
+            let lease_start = base + start_step * 31 * 86400 + start_step * 3607;
+            for &(frequency, max_day) in frequencies.iter() {
+                for payment_day in [1, 2, max_day / 2, max_day - 1, max_day] {
+                    for &offset in offsets.iter() {
+                        let horizon = lease_start + 3 * 366 * 86400;
+                        let mut period = 1;
+                        loop {
+                            let due = TimeUtils::calculate_next_payment_due(lease_start, payment_day, frequency, offset, period)
+                                .unwrap();
+                            if due > horizon {
+                                break;
+                            }
+                            for time in [due - 1, due, due + 43200] {
+                                assert_eq!(
+                                    TimeUtils::calculate_months_due(time, lease_start, payment_day, frequency, offset).unwrap(),
+                                    reference_months_due(time, lease_start, payment_day, frequency, offset),
+                                );
+                            }
+                            period += 1;
+                        }
+                        assert_eq!(
+                            TimeUtils::calculate_months_due(lease_start, lease_start, payment_day, frequency, offset).unwrap(),
+                            0
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn due_dates_fall_on_local_midnight() {
+        let lease_start = TimeUtils::date_to_timestamp(2025, 1, 20).unwrap();
+        let due = TimeUtils::calculate_next_payment_due(lease_start, 1, PAYMENT_FREQUENCY_MONTHLY, DEFAULT_UTC_OFFSET_MINUTES, 1)
+            .unwrap();
+        assert_eq!(due, TimeUtils::date_to_timestamp(2025, 2, 1).unwrap() - 8 * 3600);
+        assert_eq!(TimeUtils::timestamp_to_local_date(due, DEFAULT_UTC_OFFSET_MINUTES).unwrap(), (2025, 2, 1));
+    }
+
+    #[test]
+    fn local_dates_change_at_local_midnight() {
+        let offsets = [MIN_UTC_OFFSET_MINUTES, -300, 0, DEFAULT_UTC_OFFSET_MINUTES, MAX_UTC_OFFSET_MINUTES];
+        for &offset in offsets.iter() {
+            let midnight = TimeUtils::local_date_to_timestamp(2025, 3, 1, offset).unwrap();
+            assert_eq!(TimeUtils::timestamp_to_local_date(midnight, offset).unwrap(), (2025, 3, 1));
+            assert_eq!(TimeUtils::timestamp_to_local_date(midnight - 1, offset).unwrap(), (2025, 2, 28));
+            // 2025-03-01 為週六
+            assert_eq!(TimeUtils::weekday(midnight, offset), 6);
+            assert_eq!(TimeUtils::weekday(midnight - 1, offset), 5);
+            assert_eq!(TimeUtils::days_between(midnight - 1, midnight, offset), 1);
+            assert_eq!(TimeUtils::days_between(midnight, midnight + 86399, offset), 0);
+        }
+    }
+
+    #[test]
+    fn extreme_offsets_shift_the_local_date() {
+        // 2025-01-01 00:00 UTC（週三）
+        let utc_midnight = TimeUtils::date_to_timestamp(2025, 1, 1).unwrap();
+        assert_eq!(TimeUtils::timestamp_to_local_date(utc_midnight, MIN_UTC_OFFSET_MINUTES).unwrap(), (2024, 12, 31));
+        assert_eq!(TimeUtils::timestamp_to_local_date(utc_midnight, MAX_UTC_OFFSET_MINUTES).unwrap(), (2025, 1, 1));
+        assert_eq!(TimeUtils::weekday(utc_midnight, MIN_UTC_OFFSET_MINUTES), 2);
+        assert_eq!(TimeUtils::weekday(utc_midnight, MAX_UTC_OFFSET_MINUTES), 3);
+
+        // UTC+14 於 10:00 UTC 進入 1/2，UTC-12 於 12:00 UTC 進入 1/1
+        let plus_14_rollover = utc_midnight + 10 * 3600;
+        assert_eq!(TimeUtils::timestamp_to_local_date(plus_14_rollover - 1, MAX_UTC_OFFSET_MINUTES).unwrap(), (2025, 1, 1));
+        assert_eq!(TimeUtils::timestamp_to_local_date(plus_14_rollover, MAX_UTC_OFFSET_MINUTES).unwrap(), (2025, 1, 2));
+        assert_eq!(TimeUtils::weekday(plus_14_rollover, MAX_UTC_OFFSET_MINUTES), 4);
+        let minus_12_rollover = utc_midnight + 12 * 3600;
+        assert_eq!(TimeUtils::timestamp_to_local_date(minus_12_rollover - 1, MIN_UTC_OFFSET_MINUTES).unwrap(), (2024, 12, 31));
+        assert_eq!(TimeUtils::timestamp_to_local_date(minus_12_rollover, MIN_UTC_OFFSET_MINUTES).unwrap(), (2025, 1, 1));
+
+        // 同一段 UTC 時間在不同時區可能跨日
+        assert_eq!(TimeUtils::days_between(plus_14_rollover - 3600, plus_14_rollover, MAX_UTC_OFFSET_MINUTES), 1);
+        assert_eq!(TimeUtils::days_between(plus_14_rollover - 3600, plus_14_rollover, 0), 0);
+    }
+
+    fn local_midnight(year: i32, month: u32, day: u32) -> i64 {
+        TimeUtils::local_date_to_timestamp(year, month, day, DEFAULT_UTC_OFFSET_MINUTES).unwrap()
+    }
+
+    fn prorated(rent: u64, start: i64, end: i64, payment_day: u8, frequency: u8, period: u32) -> u64 {
+        TimeUtils::calculate_prorated_rent(rent, start, end, payment_day, frequency, DEFAULT_UTC_OFFSET_MINUTES, period)
+            .unwrap()
+    }
+
+    #[test]
+    fn monthly_first_and_last_periods_are_prorated() {
+        // 1/20 起租、每月 1 日繳費、6/15 結束：首期 1/20~2/1，末期 6/1~6/15
+        let start = local_midnight(2025, 1, 20);
+        let end = local_midnight(2025, 6, 15);
+        let monthly = PAYMENT_FREQUENCY_MONTHLY;
+        assert_eq!(
+            TimeUtils::calculate_total_payments(start, end, 1, monthly, DEFAULT_UTC_OFFSET_MINUTES).unwrap(),
+            6
+        );
+        assert_eq!(prorated(31000, start, end, 1, monthly, 0), 12000);
+        assert_eq!(prorated(30000, start, end, 1, monthly, 2), 30000);
+        assert_eq!(prorated(30000, start, end, 1, monthly, 5), 14000);
+
+        // 起租日即為繳費日時首期不需按日計算
+        let aligned_start = local_midnight(2025, 1, 1);
+        assert_eq!(prorated(31000, aligned_start, end, 1, monthly, 0), 31000);
+
+        // 續約延長結束日後，原末期以完整一期計算
+        let renewed_end = local_midnight(2026, 6, 15);
+        assert_eq!(prorated(30000, start, renewed_end, 1, monthly, 5), 30000);
+    }
+
+    #[test]
+    fn prorated_periods_follow_payment_frequency() {
+        // 週繳：1/1（週三）起租、每週一繳費、1/18 結束
+        let start = local_midnight(2025, 1, 1);
+        let end = local_midnight(2025, 1, 18);
+        let weekly = PAYMENT_FREQUENCY_WEEKLY;
+        assert_eq!(
+            TimeUtils::calculate_total_payments(start, end, 1, weekly, DEFAULT_UTC_OFFSET_MINUTES).unwrap(),
+            3
+        );
+        assert_eq!(prorated(7000, start, end, 1, weekly, 0), 5000);
+        assert_eq!(prorated(7000, start, end, 1, weekly, 1), 7000);
+        assert_eq!(prorated(7000, start, end, 1, weekly, 2), 5000);
+
+        // 季繳：2/15 起租、每季 1 日繳費，首期 2/15~3/1 佔 12/1~3/1 的 14/90
+        let start = local_midnight(2025, 2, 15);
+        let end = local_midnight(2026, 2, 15);
+        assert_eq!(prorated(9000, start, end, 1, PAYMENT_FREQUENCY_QUARTERLY, 0), 1400);
+
+        // 年繳：3/20 起租、每年 4/1 繳費，首期 3/20~4/1 佔 2024/4/1~2025/4/1 的 12/365
+        let start = local_midnight(2025, 3, 20);
+        let end = local_midnight(2027, 3, 20);
+        let yearly = PAYMENT_FREQUENCY_YEARLY;
+        assert_eq!(prorated(36500, start, end, 1, yearly, 0), 1200);
+        assert_eq!(prorated(36500, start, end, 1, yearly, 1), 36500);
+        // 末期 2026/4/1~2027/3/20 佔 353/365
+        assert_eq!(prorated(36500, start, end, 1, yearly, 2), 35300);
+    }
+}